@@ -0,0 +1,464 @@
+//! Generates the enums in `src/constants.rs` from the libav bindings, so
+//! that they match the linked FFmpeg version.
+
+use std::collections::HashSet;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+/// A Rust enum generated from the constants bindgen emits for a C enum.
+struct EnumSpec {
+    /// The name of the Rust enum.
+    name: &'static str,
+    /// The name of the C enum, which bindgen prefixes the constants with.
+    c_type: &'static str,
+    /// The prefixes of the C enum values, which are stripped from the
+    /// variant names.
+    prefixes: &'static [&'static str],
+    /// Values, without their prefix, that are left out because they alias
+    /// other values.
+    skip: &'static [&'static str],
+    /// Variant names, by value without its prefix, that do not follow the
+    /// usual conversion to camel case.
+    renames: &'static [(&'static str, &'static str)],
+}
+
+const ENUMS: &[EnumSpec] = &[
+    EnumSpec {
+        name: "AVAudioServiceType",
+        c_type: "AVAudioServiceType",
+        prefixes: &["AV_AUDIO_SERVICE_TYPE_"],
+        skip: &[],
+        renames: &[],
+    },
+    EnumSpec {
+        name: "AVChannelOrder",
+        c_type: "AVChannelOrder",
+        prefixes: &["AV_CHANNEL_ORDER_", "FF_CHANNEL_ORDER_"],
+        skip: &[],
+        renames: &[("UNSPEC", "Unspecified")],
+    },
+    EnumSpec {
+        name: "AVChannel",
+        c_type: "AVChannel",
+        prefixes: &["AV_CHAN_"],
+        skip: &[],
+        renames: &[],
+    },
+    EnumSpec {
+        name: "AVChromaLocation",
+        c_type: "AVChromaLocation",
+        prefixes: &["AVCHROMA_LOC_"],
+        skip: &[],
+        renames: &[("BOTTOMLEFT", "BottomLeft"), ("TOPLEFT", "TopLeft")],
+    },
+    EnumSpec {
+        name: "AVClassCategory",
+        c_type: "AVClassCategory",
+        prefixes: &["AV_CLASS_CATEGORY_"],
+        skip: &[],
+        renames: &[],
+    },
+    EnumSpec {
+        name: "AVCodecId",
+        c_type: "AVCodecID",
+        prefixes: &["AV_CODEC_ID_"],
+        // markers that alias the first codec of each media type
+        skip: &["FIRST_AUDIO", "FIRST_SUBTITLE", "FIRST_UNKNOWN"],
+        renames: &[
+            ("4GV", "_4Gv"),
+            ("4XM", "_4Xm"),
+            ("8BPS", "_8Bps"),
+            ("8SVX_EXP", "_8SvxExp"),
+            ("8SVX_FIB", "_8SvxFib"),
+            ("G723_1", "G723_1"),
+            ("GREMLIN_DPCM", "GremlimDpcm"),
+        ],
+    },
+    EnumSpec {
+        name: "AVColorPrimaries",
+        c_type: "AVColorPrimaries",
+        prefixes: &["AVCOL_PRI_"],
+        // older names of SMPTE428 and EBU3213
+        skip: &["SMPTEST428_1", "JEDEC_P22"],
+        renames: &[],
+    },
+    EnumSpec {
+        name: "AVColorRange",
+        c_type: "AVColorRange",
+        prefixes: &["AVCOL_RANGE_"],
+        skip: &[],
+        renames: &[],
+    },
+    EnumSpec {
+        name: "AVColorSpace",
+        c_type: "AVColorSpace",
+        prefixes: &["AVCOL_SPC_"],
+        // older name of YCGCO
+        skip: &["YCOCG"],
+        renames: &[],
+    },
+    EnumSpec {
+        name: "AVColorTransferCharacteristic",
+        c_type: "AVColorTransferCharacteristic",
+        prefixes: &["AVCOL_TRC_"],
+        // older names of SMPTE2084 and SMPTE428
+        skip: &["SMPTEST2084", "SMPTEST428_1"],
+        renames: &[
+            ("IEC61966_2_1", "Iec61966_2_1"),
+            ("IEC61966_2_4", "Iec61966_2_4"),
+        ],
+    },
+    EnumSpec {
+        name: "AVDiscard",
+        c_type: "AVDiscard",
+        prefixes: &["AVDISCARD_"],
+        skip: &[],
+        renames: &[
+            ("NONINTRA", "NonIntra"),
+            ("NONKEY", "NonKey"),
+            ("NONREF", "NonRef"),
+        ],
+    },
+    EnumSpec {
+        name: "AVFieldOrder",
+        c_type: "AVFieldOrder",
+        prefixes: &["AV_FIELD_"],
+        skip: &[],
+        renames: &[],
+    },
+    EnumSpec {
+        name: "AVFrameSideDataType",
+        c_type: "AVFrameSideDataType",
+        prefixes: &["AV_FRAME_DATA_"],
+        skip: &[],
+        renames: &[
+            ("DISPLAYMATRIX", "DisplayMatrix"),
+            ("MATRIXENCODING", "MatrixEncoding"),
+            ("PANSCAN", "PanScan"),
+            ("REPLAYGAIN", "ReplayGain"),
+        ],
+    },
+    EnumSpec {
+        name: "AVMatrixEncoding",
+        c_type: "AVMatrixEncoding",
+        prefixes: &["AV_MATRIX_ENCODING_"],
+        skip: &[],
+        renames: &[("DOLBYEX", "DolbyEx"), ("DOLBYHEADPHONE", "DolbyHeadphone")],
+    },
+    EnumSpec {
+        name: "AVMediaType",
+        c_type: "AVMediaType",
+        prefixes: &["AVMEDIA_TYPE_"],
+        skip: &[],
+        renames: &[],
+    },
+    EnumSpec {
+        name: "AVOptionType",
+        c_type: "AVOptionType",
+        prefixes: &["AV_OPT_TYPE_"],
+        skip: &[],
+        renames: &[("CHLAYOUT", "ChLayout")],
+    },
+    EnumSpec {
+        name: "AVPacketSideDataType",
+        c_type: "AVPacketSideDataType",
+        prefixes: &["AV_PKT_DATA_"],
+        skip: &[],
+        renames: &[
+            ("DISPLAYMATRIX", "DisplayMatrix"),
+            ("REPLAYGAIN", "ReplayGain"),
+        ],
+    },
+    EnumSpec {
+        name: "AVPictureStructure",
+        c_type: "AVPictureStructure",
+        prefixes: &["AV_PICTURE_STRUCTURE_"],
+        skip: &[],
+        renames: &[],
+    },
+    EnumSpec {
+        name: "AVPictureType",
+        c_type: "AVPictureType",
+        prefixes: &["AV_PICTURE_TYPE_"],
+        skip: &[],
+        renames: &[],
+    },
+    EnumSpec {
+        name: "AVPixelFormat",
+        c_type: "AVPixelFormat",
+        prefixes: &["AV_PIX_FMT_"],
+        // older names of YA8 and GBRP
+        skip: &["Y400A", "GRAY8A", "GBR24P"],
+        renames: &[("0BGR", "ZeroBgr"), ("0RGB", "ZeroRgb")],
+    },
+    EnumSpec {
+        name: "AVSampleFormat",
+        c_type: "AVSampleFormat",
+        prefixes: &["AV_SAMPLE_FMT_"],
+        skip: &[],
+        renames: &[],
+    },
+    EnumSpec {
+        name: "AVSubtitleType",
+        c_type: "AVSubtitleType",
+        prefixes: &["SUBTITLE_"],
+        skip: &[],
+        renames: &[],
+    },
+];
+
+/// A constant declared by the bindings.
+struct Constant {
+    docs: Vec<String>,
+    name: String,
+    ty: String,
+    value: i64,
+}
+
+fn main() {
+    let bindings_path = env::var("DEP_AVCODEC_BINDINGS")
+        .expect("libavcodec-sys did not report the path of its bindings");
+    println!("cargo:rerun-if-changed={bindings_path}");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let bindings = fs::read_to_string(&bindings_path).expect("couldn't read libavcodec bindings");
+    let constants = parse_constants(&bindings);
+
+    let mut out = String::new();
+    for spec in ENUMS {
+        generate_enum(&mut out, spec, &constants, &bindings);
+    }
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("constants.rs");
+    fs::write(out_path, out).expect("couldn't write generated constants");
+}
+
+fn generate_enum(out: &mut String, spec: &EnumSpec, constants: &[Constant], bindings: &str) {
+    let repr = enum_repr(bindings, spec.c_type);
+    let mut values = HashSet::new();
+
+    writeln!(
+        out,
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]"
+    )
+    .unwrap();
+    writeln!(out, "#[repr({repr})]").unwrap();
+    writeln!(out, "pub enum {} {{", spec.name).unwrap();
+
+    for constant in constants.iter().filter(|c| c.ty == spec.c_type) {
+        let Some(value) = constant
+            .name
+            .strip_prefix(spec.c_type)
+            .and_then(|name| name.strip_prefix('_'))
+        else {
+            continue;
+        };
+
+        let Some(suffix) = spec.prefixes.iter().find_map(|p| value.strip_prefix(p)) else {
+            continue;
+        };
+
+        if spec.skip.contains(&suffix) {
+            continue;
+        }
+
+        // discriminants must be unique, so only the first of several
+        // aliases is kept. Known aliases are in the skip lists, so this only
+        // warns about ones added by a newer FFmpeg.
+        if !values.insert(constant.value) {
+            println!(
+                "cargo:warning=skipping {} as it aliases another value",
+                constant.name
+            );
+            continue;
+        }
+
+        for doc in &constant.docs {
+            writeln!(out, "    #[doc = \"{doc}\"]").unwrap();
+        }
+
+        writeln!(
+            out,
+            "    {} = {},",
+            variant_name(spec, suffix),
+            constant.name
+        )
+        .unwrap();
+    }
+
+    writeln!(out, "}}\n").unwrap();
+}
+
+// e.g. AAC_LATM -> AacLatm
+fn variant_name(spec: &EnumSpec, suffix: &str) -> String {
+    if let Some((_, name)) = spec.renames.iter().find(|(value, _)| *value == suffix) {
+        return name.to_string();
+    }
+
+    let mut name = String::new();
+    for part in suffix.split('_').filter(|part| !part.is_empty()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.extend(chars.map(|c| c.to_ascii_lowercase()));
+        }
+    }
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    name
+}
+
+// bindgen declares C enums as an alias of their underlying integer type
+fn enum_repr(bindings: &str, c_type: &str) -> &'static str {
+    let declaration = format!("pub type {c_type} ");
+    let start = bindings
+        .find(&declaration)
+        .unwrap_or_else(|| panic!("no type alias for {c_type} in the bindings"));
+    let end = start + bindings[start..].find(';').unwrap();
+    let ty: String = bindings[start..end].split_whitespace().collect();
+
+    if ty.ends_with("c_uint") { "u32" } else { "i32" }
+}
+
+// Finds every `pub const NAME: TYPE = VALUE;` with an integer value in the
+// bindings, along with its doc attributes. This only relies on tokens, as the
+// bindings are not formatted if rustfmt is unavailable.
+fn parse_constants(bindings: &str) -> Vec<Constant> {
+    let mut constants = Vec::new();
+    let mut docs = Vec::new();
+    let mut rest = bindings.trim_start();
+
+    while !rest.is_empty() {
+        if let Some(after) = strip_tokens(rest, &["#", "[", "doc", "="]) {
+            let (doc, after) = parse_string(after);
+            docs.push(doc);
+            rest = strip_tokens(after, &["]"]).unwrap_or(after);
+        } else if let Some(after) = strip_tokens(rest, &["pub", "const"]) {
+            let end = after.find(';').unwrap_or(after.len());
+            if let Some(constant) = parse_constant(&after[..end], std::mem::take(&mut docs)) {
+                constants.push(constant);
+            }
+            rest = &after[(end + 1).min(after.len())..];
+        } else {
+            // any other item, skipped up to the next attribute or item
+            docs.clear();
+            // the first character may be multi-byte, e.g. in a doc comment
+            let first = rest.chars().next().map_or(1, char::len_utf8);
+            let next = rest[first..]
+                .find(['#', '\n', ';', '}'])
+                .map_or(rest.len(), |i| first + i + 1);
+            rest = &rest[next.min(rest.len())..];
+        }
+
+        rest = rest.trim_start();
+    }
+
+    constants
+}
+
+fn parse_constant(declaration: &str, docs: Vec<String>) -> Option<Constant> {
+    let (name, rest) = declaration.split_once(':')?;
+    let (ty, value) = rest.split_once('=')?;
+    let value: String = value.split_whitespace().collect();
+
+    Some(Constant {
+        docs,
+        name: name.trim().to_string(),
+        ty: ty.trim().to_string(),
+        value: value.parse().ok()?,
+    })
+}
+
+// strips a sequence of tokens, which may be separated by whitespace
+fn strip_tokens<'a>(mut input: &'a str, tokens: &[&str]) -> Option<&'a str> {
+    for token in tokens {
+        input = input.trim_start().strip_prefix(token)?;
+    }
+
+    Some(input)
+}
+
+// parses a string literal, returning it still escaped
+fn parse_string(input: &str) -> (String, &str) {
+    let input = input.trim_start();
+    let Some(body) = input.strip_prefix('"') else {
+        return (String::new(), input);
+    };
+
+    let mut escaped = false;
+    for (i, c) in body.char_indices() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return (body[..i].to_string(), &body[i + 1..]),
+            _ => escaped = false,
+        }
+    }
+
+    (body.to_string(), "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_constants_with_docs() {
+        let bindings = r#"
+            pub const AVMediaType_AVMEDIA_TYPE_UNKNOWN: AVMediaType = -1;
+            #[doc = " Usually treated as AVMEDIA_TYPE_DATA"]
+            pub const AVMediaType_AVMEDIA_TYPE_VIDEO: AVMediaType = 0;
+            pub type AVMediaType = ::std::os::raw::c_int;
+        "#;
+
+        let constants = parse_constants(bindings);
+
+        assert_eq!(constants.len(), 2);
+        assert_eq!(constants[0].name, "AVMediaType_AVMEDIA_TYPE_UNKNOWN");
+        assert_eq!(constants[0].ty, "AVMediaType");
+        assert_eq!(constants[0].value, -1);
+        assert!(constants[0].docs.is_empty());
+        assert_eq!(constants[1].value, 0);
+        assert_eq!(constants[1].docs, [" Usually treated as AVMEDIA_TYPE_DATA"]);
+    }
+
+    #[test]
+    fn parses_unformatted_bindings() {
+        let bindings = "pub const A_X:A=1;pub const A_Y : A = 2 ;pub type A=u32;";
+        let values: Vec<_> = parse_constants(bindings)
+            .iter()
+            .map(|c| (c.name.clone(), c.value))
+            .collect();
+
+        assert_eq!(values, [("A_X".to_string(), 1), ("A_Y".to_string(), 2)]);
+    }
+
+    #[test]
+    fn skips_non_ascii_items() {
+        let bindings = "\u{e9}t\u{e9} = 1;\n// \u{b5}s\npub const A_X: A = 3;";
+        let constants = parse_constants(bindings);
+
+        assert_eq!(constants.len(), 1);
+        assert_eq!(constants[0].value, 3);
+    }
+
+    #[test]
+    fn skips_non_integer_constants() {
+        let bindings = r#"pub const A_X: &[u8; 2] = b"x\0";"#;
+        assert!(parse_constants(bindings).is_empty());
+    }
+
+    #[test]
+    fn converts_variant_names() {
+        let spec = ENUMS.iter().find(|spec| spec.name == "AVCodecId").unwrap();
+
+        assert_eq!(variant_name(spec, "AAC_LATM"), "AacLatm");
+        assert_eq!(variant_name(spec, "H264"), "H264");
+        assert_eq!(variant_name(spec, "4XM"), "_4Xm");
+        assert_eq!(variant_name(spec, "012V"), "_012v");
+    }
+}
@@ -39,26 +39,22 @@ fn main() -> Result<()> {
     // Open input file
     let mut input_format_ctx = FormatContext::open(&args.input)?;
 
-    // Find audio stream
-    let mut audio_stream = input_format_ctx
-        .streams()
-        .find(|s| matches!(s.codec_type(), AVMediaType::Audio))
-        .ok_or_else(|| anyhow::anyhow!("no audio stream found"))?;
-
-    // Get decoder
-    let decoder = Codec::find_decoder(audio_stream.codec_id())
-        .ok_or_else(|| anyhow::anyhow!("failed to find decoder"))?;
+    // Find audio stream and its decoder
+    let (audio_stream, decoder) = input_format_ctx.best_stream(AVMediaType::Audio, None)?;
 
     // Create decoder context
     let mut input_codec_ctx = CodecContext::new(&decoder)?;
     audio_stream.apply_parameters_to_context(&mut input_codec_ctx)?;
+    let audio_stream_index = audio_stream.index();
 
     // Open decoder
     input_codec_ctx.open(&decoder)?;
 
     // Get input format details
     let in_sample_rate = input_codec_ctx.sample_rate();
-    let in_sample_fmt = input_codec_ctx.sample_format();
+    let in_sample_fmt = input_codec_ctx
+        .sample_format()
+        .ok_or_else(|| anyhow::anyhow!("unknown sample format"))?;
     let in_channels = input_codec_ctx.channel_count();
 
     println!(
@@ -100,7 +96,8 @@ fn main() -> Result<()> {
     output_codec_ctx.set_sample_rate(out_sample_rate);
     output_codec_ctx.set_sample_format(out_sample_fmt);
     output_codec_ctx.set_channel_count(out_channels);
-    output_codec_ctx.set_time_base(output_stream.time_base());
+    let output_time_base = output_stream.time_base();
+    output_codec_ctx.set_time_base(output_time_base);
 
     // Open encoder
     output_codec_ctx.open(&encoder)?;
@@ -139,7 +136,7 @@ fn main() -> Result<()> {
     while input_format_ctx.read_packet(&mut packet)? {
         tracing::trace!("got packet: {packet:?}");
 
-        if packet.stream_index() == audio_stream.index() {
+        if packet.stream_index() == audio_stream_index {
             // Send packet to decoder
             input_codec_ctx.send_packet(&packet)?;
 
@@ -191,7 +188,7 @@ fn main() -> Result<()> {
                                     // Rescale timestamps to output stream time base
                                     enc_packet.rescale_ts(
                                         output_codec_ctx.time_base().into(),
-                                        output_stream.time_base().into(),
+                                        output_time_base.into(),
                                     );
 
                                     // Write the packet
@@ -252,7 +249,7 @@ fn main() -> Result<()> {
                             // Rescale timestamps to output stream time base
                             enc_packet.rescale_ts(
                                 output_codec_ctx.time_base().into(),
-                                output_stream.time_base().into(),
+                                output_time_base.into(),
                             );
 
                             // Write the packet
@@ -281,10 +278,7 @@ fn main() -> Result<()> {
                 enc_packet.set_stream_index(0);
 
                 // Rescale timestamps to output stream time base
-                enc_packet.rescale_ts(
-                    output_codec_ctx.time_base().into(),
-                    output_stream.time_base().into(),
-                );
+                enc_packet.rescale_ts(output_codec_ctx.time_base().into(), output_time_base.into());
 
                 // Write the packet
                 output_format_ctx.write_frame_interleaved(&mut enc_packet)?;
@@ -1,8 +1,7 @@
 use anyhow::Result;
 use image::Rgb;
 use libavcodec::{
-    AVDiscard, AVMediaType, AVPixelFormat, Codec, CodecContext, FormatContext, Frame, Packet,
-    SwsContext,
+    AVDiscard, AVMediaType, AVPixelFormat, CodecContext, FormatContext, Frame, Packet, SwsContext,
 };
 use std::fs;
 use std::path::Path;
@@ -25,19 +24,17 @@ fn main() -> Result<()> {
     // Open input file
     let mut format_ctx = FormatContext::open(&input_file)?;
 
-    // Find video stream
-    let video_stream = format_ctx
-        .streams()
-        .find(|s| matches!(s.codec_type(), AVMediaType::Video))
-        .expect("no video stream found");
-
-    // Get decoder
-    let decoder = Codec::find_decoder(video_stream.codec_id()).expect("failed to find decoder");
+    // Find video stream and its decoder
+    let (video_stream, decoder) = format_ctx.best_stream(AVMediaType::Video, None)?;
 
     // Create decoder context
     let mut codec_ctx = CodecContext::new(&decoder)?;
     video_stream.apply_parameters_to_context(&mut codec_ctx)?;
 
+    // Get stream index and timebase for PTS conversion
+    let video_stream_index = video_stream.index();
+    let time_base = video_stream.time_base();
+
     // Only decode keyframes
     codec_ctx.set_skip_frame(AVDiscard::NonKey);
 
@@ -51,7 +48,7 @@ fn main() -> Result<()> {
     // Get frame dimensions
     let width = codec_ctx.width();
     let height = codec_ctx.height();
-    let src_pix_fmt = codec_ctx.pixel_format();
+    let src_pix_fmt = codec_ctx.pixel_format().expect("unknown pixel format");
 
     // Allocate buffer for RGB frame
     rgb_frame.allocate_image_buffer_av(
@@ -69,18 +66,14 @@ fn main() -> Result<()> {
         width,
         height,
         AVPixelFormat::Rgb24,
-        0,
     )?;
 
     // Create packet for reading
     let mut packet = Packet::new()?;
 
-    // Get stream timebase for PTS conversion
-    let time_base = video_stream.time_base();
-
     // Read frames
     while format_ctx.read_packet(&mut packet)? {
-        if packet.stream_index() == video_stream.index() {
+        if packet.stream_index() == video_stream_index {
             // Send packet to decoder
             codec_ctx.send_packet(&packet)?;
 
@@ -0,0 +1,310 @@
+use libavcodec_sys as sys;
+use num_traits::FromPrimitive;
+use rayon::prelude::*;
+
+use crate::error::{Error, Result};
+use crate::frame::Frame;
+use crate::{AVPixelFormat, PixelFormatDescriptor, SwsContext, SwsOptions};
+
+/// A scaler that splits frames into horizontal bands and scales them in
+/// parallel on the rayon thread pool, each band with its own [`SwsContext`].
+///
+/// This works with any libswscale, unlike [`SwsOptions::threads`]. Bands are
+/// scaled independently though, so scaling filters do not reach across band
+/// edges, which can leave faint seams with the sharper algorithms.
+pub struct BandScaler {
+    contexts: Vec<SwsContext>,
+    bands: Vec<Band>,
+    // size and format of the frames the contexts were built for
+    src: Option<(i32, i32, AVPixelFormat)>,
+    band_count: usize,
+    dst_width: usize,
+    dst_height: usize,
+    dst_pix_fmt: AVPixelFormat,
+    options: SwsOptions,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Band {
+    src_y: usize,
+    src_height: usize,
+    dst_y: usize,
+    dst_height: usize,
+}
+
+// frame planes shared between the band workers, which write to disjoint rows
+#[derive(Clone, Copy)]
+struct Planes {
+    data: [*mut u8; 4],
+    linesize: [i32; 4],
+}
+
+unsafe impl Send for Planes {}
+unsafe impl Sync for Planes {}
+
+impl BandScaler {
+    /// Creates a scaler that uses one band per thread of the rayon pool.
+    pub fn new(
+        dst_width: usize,
+        dst_height: usize,
+        dst_pix_fmt: AVPixelFormat,
+        options: SwsOptions,
+    ) -> Self {
+        Self::with_bands(
+            dst_width,
+            dst_height,
+            dst_pix_fmt,
+            options,
+            rayon::current_num_threads(),
+        )
+    }
+
+    pub fn with_bands(
+        dst_width: usize,
+        dst_height: usize,
+        dst_pix_fmt: AVPixelFormat,
+        options: SwsOptions,
+        band_count: usize,
+    ) -> Self {
+        BandScaler {
+            contexts: Vec::new(),
+            bands: Vec::new(),
+            src: None,
+            band_count: band_count.max(1),
+            dst_width,
+            dst_height,
+            dst_pix_fmt,
+            // the bands are the threads
+            options: SwsOptions {
+                threads: None,
+                ..options
+            },
+        }
+    }
+
+    /// Scales `src` into a newly allocated frame, which also gets the
+    /// properties of `src` such as its timestamps.
+    pub fn scale(&mut self, src: &Frame) -> Result<Frame> {
+        let src_pix_fmt = AVPixelFormat::from_i32(src.format()).ok_or(Error::new(-libc::EINVAL))?;
+        self.configure(src.width(), src.height(), src_pix_fmt)?;
+
+        let mut dst = Frame::new()?;
+        unsafe {
+            dst.inner_mut().width = self.dst_width as i32;
+            dst.inner_mut().height = self.dst_height as i32;
+            dst.inner_mut().format = self.dst_pix_fmt as i32;
+
+            let ret = sys::av_frame_get_buffer(dst.inner_mut(), 0);
+            if ret < 0 {
+                return Err(Error::new(ret));
+            }
+        }
+        dst.copy_props(src)?;
+
+        let src_planes = planes(src);
+        let dst_planes = planes(&dst);
+        let src_desc = descriptor(src_pix_fmt)?;
+        let dst_desc = descriptor(self.dst_pix_fmt)?;
+
+        let jobs: Vec<_> = self
+            .bands
+            .iter()
+            .map(|band| {
+                (
+                    band.src_height,
+                    offset_planes(src_planes, &src_desc, band.src_y),
+                    offset_planes(dst_planes, &dst_desc, band.dst_y),
+                )
+            })
+            .collect();
+
+        self.contexts
+            .par_iter_mut()
+            .zip(jobs.par_iter())
+            .try_for_each(|(ctx, (src_height, src, dst))| unsafe {
+                ctx.scale_planes(
+                    &src.data.map(|ptr| ptr as *const u8),
+                    &src.linesize,
+                    0,
+                    *src_height as i32,
+                    &dst.data,
+                    &dst.linesize,
+                )
+            })?;
+
+        Ok(dst)
+    }
+
+    fn configure(&mut self, width: i32, height: i32, pix_fmt: AVPixelFormat) -> Result<()> {
+        if self.src == Some((width, height, pix_fmt)) {
+            return Ok(());
+        }
+
+        self.src = None;
+
+        if width <= 0 || height <= 0 || self.dst_width == 0 || self.dst_height == 0 {
+            return Err(Error::new(-libc::EINVAL));
+        }
+
+        self.bands = split_bands(
+            height as usize,
+            vertical_alignment(pix_fmt)?,
+            self.dst_height,
+            vertical_alignment(self.dst_pix_fmt)?,
+            self.band_count,
+        );
+
+        self.contexts = self
+            .bands
+            .iter()
+            .map(|band| {
+                SwsContext::get_context_with_options(
+                    width as usize,
+                    band.src_height,
+                    pix_fmt,
+                    self.dst_width,
+                    band.dst_height,
+                    self.dst_pix_fmt,
+                    self.options,
+                )
+            })
+            .collect::<Result<_>>()?;
+
+        self.src = Some((width, height, pix_fmt));
+        Ok(())
+    }
+}
+
+// splits the rows into bands whose first rows are aligned to the chroma
+// subsampling of both formats, so that every band starts on a chroma row.
+// both heights must be non-zero
+fn split_bands(
+    src_height: usize,
+    src_align: usize,
+    dst_height: usize,
+    dst_align: usize,
+    count: usize,
+) -> Vec<Band> {
+    let mut bands: Vec<Band> = Vec::with_capacity(count);
+    let (mut src_y, mut dst_y) = (0, 0);
+
+    for i in 1..=count {
+        let (src_end, dst_end) = if i == count {
+            (src_height, dst_height)
+        } else {
+            let dst_end = i * dst_height / count / dst_align * dst_align;
+            let src_end = dst_end * src_height / dst_height / src_align * src_align;
+            (src_end, dst_end)
+        };
+
+        if src_end > src_y && dst_end > dst_y {
+            bands.push(Band {
+                src_y,
+                src_height: src_end - src_y,
+                dst_y,
+                dst_height: dst_end - dst_y,
+            });
+
+            src_y = src_end;
+            dst_y = dst_end;
+        }
+    }
+
+    bands
+}
+
+fn descriptor(pix_fmt: AVPixelFormat) -> Result<PixelFormatDescriptor> {
+    pix_fmt.descriptor().ok_or(Error::new(-libc::EINVAL))
+}
+
+fn vertical_alignment(pix_fmt: AVPixelFormat) -> Result<usize> {
+    Ok(1 << descriptor(pix_fmt)?.chroma_shift_h())
+}
+
+fn planes(frame: &Frame) -> Planes {
+    let inner = frame.inner();
+    let mut planes = Planes {
+        data: [std::ptr::null_mut(); 4],
+        linesize: [0; 4],
+    };
+
+    planes.data.copy_from_slice(&inner.data[..4]);
+    planes.linesize.copy_from_slice(&inner.linesize[..4]);
+    planes
+}
+
+// the planes of the image starting at row `y`
+fn offset_planes(mut planes: Planes, desc: &PixelFormatDescriptor, y: usize) -> Planes {
+    for (plane, ptr) in planes.data.iter_mut().enumerate() {
+        if ptr.is_null() || (desc.is_palette() && plane == 1) {
+            continue;
+        }
+
+        let rows = if (plane == 1 || plane == 2) && !desc.is_rgb() {
+            y >> desc.chroma_shift_h()
+        } else {
+            y
+        };
+
+        *ptr = unsafe { ptr.offset(rows as isize * planes.linesize[plane] as isize) };
+    }
+
+    planes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // checks that the bands cover both images without gaps or overlaps
+    fn assert_contiguous(bands: &[Band], src_height: usize, dst_height: usize) {
+        let (mut src_y, mut dst_y) = (0, 0);
+
+        for band in bands {
+            assert_eq!(band.src_y, src_y);
+            assert_eq!(band.dst_y, dst_y);
+            assert!(band.src_height > 0 && band.dst_height > 0);
+
+            src_y += band.src_height;
+            dst_y += band.dst_height;
+        }
+
+        assert_eq!((src_y, dst_y), (src_height, dst_height));
+    }
+
+    #[test]
+    fn splits_evenly() {
+        let bands = split_bands(1080, 2, 720, 2, 4);
+
+        assert_eq!(bands.len(), 4);
+        assert_contiguous(&bands, 1080, 720);
+        assert!(bands.iter().all(|band| band.dst_height == 180));
+    }
+
+    #[test]
+    fn aligns_band_starts() {
+        let bands = split_bands(1081, 2, 479, 4, 3);
+
+        assert_contiguous(&bands, 1081, 479);
+        for band in &bands {
+            assert_eq!(band.src_y % 2, 0);
+            assert_eq!(band.dst_y % 4, 0);
+        }
+    }
+
+    #[test]
+    fn merges_bands_smaller_than_a_row() {
+        let bands = split_bands(2, 1, 3, 1, 8);
+
+        assert!(bands.len() <= 2);
+        assert_contiguous(&bands, 2, 3);
+    }
+
+    #[test]
+    fn uses_a_single_band() {
+        let bands = split_bands(100, 2, 50, 2, 1);
+
+        assert_eq!(bands.len(), 1);
+        assert_contiguous(&bands, 100, 50);
+    }
+}
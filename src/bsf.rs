@@ -0,0 +1,162 @@
+use std::ffi::CString;
+use std::ptr::{self, NonNull};
+
+use libavcodec_sys as sys;
+
+use crate::error::{Error, Result};
+use crate::{AVError, CodecParameters, Packet, Rational, Stream, StreamMut};
+
+/// Wrapper around a bitstream filter such as `h264_mp4toannexb`,
+/// `aac_adtstoasc` or `extract_extradata`.
+pub struct BitstreamFilter {
+    inner: NonNull<sys::AVBSFContext>,
+}
+
+unsafe impl Send for BitstreamFilter {}
+
+impl AsRef<sys::AVBSFContext> for BitstreamFilter {
+    fn as_ref(&self) -> &sys::AVBSFContext {
+        unsafe { self.inner.as_ref() }
+    }
+}
+
+impl AsMut<sys::AVBSFContext> for BitstreamFilter {
+    fn as_mut(&mut self) -> &mut sys::AVBSFContext {
+        unsafe { self.inner.as_mut() }
+    }
+}
+
+impl BitstreamFilter {
+    /// Creates the filter named `name` and initializes it with the codec
+    /// parameters and time base of `stream`.
+    pub fn new(name: &str, stream: &Stream<'_>) -> Result<Self> {
+        let name_cstr = CString::new(name).map_err(|_| Error::NulByte)?;
+
+        let filter = unsafe { sys::av_bsf_get_by_name(name_cstr.as_ptr()) };
+        if filter.is_null() {
+            return Err(Error::Av(AVError::BsfNotFound));
+        }
+
+        let mut inner = ptr::null_mut();
+        let ret = unsafe { sys::av_bsf_alloc(filter, &mut inner) };
+        if ret < 0 {
+            return Err(Error::new(ret));
+        }
+
+        // from here on, dropping the wrapper frees the context
+        let mut bsf = BitstreamFilter {
+            inner: NonNull::new(inner).ok_or(Error::Alloc)?,
+        };
+
+        unsafe {
+            let ret = sys::avcodec_parameters_copy(bsf.as_mut().par_in, stream.as_ref().codecpar);
+            if ret < 0 {
+                return Err(Error::new(ret));
+            }
+
+            bsf.as_mut().time_base_in = stream.time_base().into();
+
+            let ret = sys::av_bsf_init(bsf.as_mut_ptr());
+            if ret < 0 {
+                return Err(Error::new(ret));
+            }
+        }
+
+        Ok(bsf)
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut sys::AVBSFContext {
+        self.inner.as_ptr()
+    }
+
+    pub fn as_ptr(&self) -> *const sys::AVBSFContext {
+        self.inner.as_ptr()
+    }
+
+    /// The codec parameters of the filtered packets. These should be copied
+    /// to the destination stream, since filters like `extract_extradata` or
+    /// `aac_adtstoasc` change the extradata.
+    pub fn output_parameters(&self) -> &CodecParameters {
+        unsafe { CodecParameters::from_raw(self.as_ref().par_out) }
+    }
+
+    /// Copies the output codec parameters onto `stream`.
+    pub fn copy_output_parameters_to(&self, stream: &mut StreamMut<'_>) -> Result<()> {
+        stream.codecpar_mut().copy_from(self.output_parameters())
+    }
+
+    /// The time base of the filtered packets.
+    pub fn output_time_base(&self) -> Rational {
+        self.as_ref().time_base_out.into()
+    }
+
+    /// Submits a packet for filtering, taking its contents and leaving it
+    /// blank. Passing `None` signals the end of the stream.
+    ///
+    /// Returns `false` if the filter's output has to be drained with
+    /// `receive_packet` before more input can be accepted, in which case the
+    /// packet is left untouched.
+    pub fn send_packet(&mut self, packet: Option<&mut Packet>) -> Result<bool> {
+        let packet_ptr = packet.map_or(ptr::null_mut(), |p| p.as_mut() as *mut _);
+        let ret = unsafe { sys::av_bsf_send_packet(self.as_mut_ptr(), packet_ptr) };
+
+        if ret == AVError::Again as i32 {
+            Ok(false)
+        } else if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(true)
+        }
+    }
+
+    /// Retrieves a filtered packet.
+    ///
+    /// Returns `false` if the filter needs more input, or if it has been
+    /// fully drained after the end of the stream was signalled.
+    pub fn receive_packet(&mut self, packet: &mut Packet) -> Result<bool> {
+        let ret = unsafe { sys::av_bsf_receive_packet(self.as_mut_ptr(), packet.as_mut()) };
+
+        if ret == AVError::Again as i32 || ret == AVError::Eof as i32 {
+            Ok(false)
+        } else if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(true)
+        }
+    }
+
+    /// Filters a single packet and returns all packets the filter produced
+    /// in response. Passing `None` flushes the filter.
+    pub fn filter(&mut self, mut packet: Option<&mut Packet>) -> Result<Vec<Packet>> {
+        let mut output = Vec::new();
+
+        loop {
+            let sent = self.send_packet(packet.as_deref_mut())?;
+
+            loop {
+                let mut out = Packet::new()?;
+                if !self.receive_packet(&mut out)? {
+                    break;
+                }
+                output.push(out);
+            }
+
+            if sent {
+                break;
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Resets the internal state of the filter, e.g. after seeking.
+    pub fn reset(&mut self) {
+        unsafe { sys::av_bsf_flush(self.as_mut_ptr()) }
+    }
+}
+
+impl Drop for BitstreamFilter {
+    fn drop(&mut self) {
+        unsafe { sys::av_bsf_free(&mut self.inner.as_ptr()) }
+    }
+}
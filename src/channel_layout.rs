@@ -1,12 +1,20 @@
-use std::{fmt::Debug, mem::MaybeUninit};
+use std::{
+    ffi::{CString, c_char},
+    fmt::{self, Debug, Display},
+    mem::MaybeUninit,
+    str::FromStr,
+};
 
 use libavcodec_sys::av_channel_layout_default;
 use num_traits::FromPrimitive;
 
-use crate::{AVChannelOrder, sys};
+use crate::{AVChannel, AVChannelOrder, Error, error::Result, sys};
 
-#[derive(Clone, Copy)]
-pub struct ChannelLayout(pub sys::AVChannelLayout);
+/// An audio channel layout.
+///
+/// Layouts with a custom order own a heap-allocated channel map, so this
+/// type releases it on drop and deep-copies it on clone.
+pub struct ChannelLayout(pub(crate) sys::AVChannelLayout);
 
 unsafe impl Send for ChannelLayout {}
 
@@ -15,23 +23,29 @@ impl ChannelLayout {
         self.0.nb_channels as usize
     }
 
-    pub fn set_count(&mut self, count: usize) {
+    /// Overwrites the channel count without touching the rest of the layout.
+    ///
+    /// # Safety
+    ///
+    /// For a custom order, `count` must not exceed the length of the channel
+    /// map, which libav indexes up to the count.
+    pub unsafe fn set_count(&mut self, count: usize) {
         self.0.nb_channels = count as i32;
     }
 
-    pub fn order(&self) -> AVChannelOrder {
-        #[cfg(unix)]
-        {
-            AVChannelOrder::from_u32(self.0.order).unwrap()
-        }
-
-        #[cfg(windows)]
-        {
-            AVChannelOrder::from_i32(self.0.order).unwrap()
-        }
+    /// The channel order, or `None` if it is not known to this crate.
+    pub fn order(&self) -> Option<AVChannelOrder> {
+        AVChannelOrder::from_i64(self.0.order as i64)
     }
 
-    pub fn set_order(&mut self, order: AVChannelOrder) {
+    /// Overwrites the channel order without converting the layout.
+    ///
+    /// # Safety
+    ///
+    /// The layout must already hold data valid for `order`. Switching to or
+    /// from [`AVChannelOrder::Custom`] reinterprets the channel mask as a
+    /// pointer to the channel map, or leaks the map.
+    pub unsafe fn set_order(&mut self, order: AVChannelOrder) {
         self.0.order = order as _;
     }
 
@@ -42,6 +56,245 @@ impl ChannelLayout {
             cl.assume_init()
         })
     }
+
+    /// Creates a native-order layout from a bitmask of `AV_CH_*` flags.
+    pub fn from_mask(mask: u64) -> Result<Self> {
+        let mut layout = Self::empty();
+        let ret = unsafe { sys::av_channel_layout_from_mask(&mut layout.0, mask) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(layout)
+        }
+    }
+
+    /// Parses a layout description such as `"5.1(side)"`, `"FL+FR+LFE"` or
+    /// `"ambisonic 1"`.
+    pub fn from_string(description: &str) -> Result<Self> {
+        let description = CString::new(description).map_err(|_| Error::NulByte)?;
+        let mut layout = Self::empty();
+        let ret =
+            unsafe { sys::av_channel_layout_from_string(&mut layout.0, description.as_ptr()) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(layout)
+        }
+    }
+
+    /// Creates a custom-order layout with the given channels, in order.
+    pub fn custom(channels: &[AVChannel]) -> Result<Self> {
+        let mut layout = Self::empty();
+        let ret =
+            unsafe { sys::av_channel_layout_custom_init(&mut layout.0, channels.len() as i32) };
+
+        if ret < 0 {
+            return Err(Error::new(ret));
+        }
+
+        for (i, &channel) in channels.iter().enumerate() {
+            unsafe { (*layout.0.u.map.add(i)).id = channel as _ };
+        }
+
+        Ok(layout)
+    }
+
+    /// Creates an ambisonic layout of the given order, optionally followed by
+    /// non-diegetic channels described by a bitmask of `AV_CH_*` flags.
+    pub fn ambisonic(order: u32, extra_mask: u64) -> Result<Self> {
+        let mut layout = Self::empty();
+        let components = (order + 1) * (order + 1);
+
+        layout.0.order = AVChannelOrder::Ambisonic as _;
+        layout.0.nb_channels = (components + extra_mask.count_ones()) as i32;
+        layout.0.u.mask = extra_mask;
+
+        if layout.is_valid() {
+            Ok(layout)
+        } else {
+            Err(Error::new(-libc::EINVAL))
+        }
+    }
+
+    fn empty() -> Self {
+        Self(unsafe { MaybeUninit::zeroed().assume_init() })
+    }
+
+    /// Copies a layout owned by libav.
+    pub(crate) fn from_raw(raw: &sys::AVChannelLayout) -> Result<Self> {
+        let mut layout = Self::empty();
+        let ret = unsafe { sys::av_channel_layout_copy(&mut layout.0, raw) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(layout)
+        }
+    }
+
+    /// Copies the layout, failing if the channel map of a custom-order layout
+    /// cannot be allocated.
+    pub fn try_clone(&self) -> Result<Self> {
+        Self::from_raw(&self.0)
+    }
+
+    /// Overwrites a layout owned by libav with a copy of this one.
+    pub(crate) fn copy_to(&self, dst: &mut sys::AVChannelLayout) -> Result<()> {
+        let ret = unsafe { sys::av_channel_layout_copy(dst, &self.0) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn as_ptr(&self) -> *const sys::AVChannelLayout {
+        &self.0
+    }
+
+    /// The channel bitmask, if this is a native-order layout.
+    pub fn mask(&self) -> Option<u64> {
+        if self.order() == Some(AVChannelOrder::Native) {
+            Some(unsafe { self.0.u.mask })
+        } else {
+            None
+        }
+    }
+
+    /// Whether the layout is internally consistent.
+    pub fn is_valid(&self) -> bool {
+        unsafe { sys::av_channel_layout_check(&self.0) == 1 }
+    }
+
+    /// The channel at `index`. Ambisonic components are reported as
+    /// [`AVChannel::AmbisonicBase`]; use [`Self::ambisonic_component`] to get
+    /// their channel number.
+    pub fn channel(&self, index: usize) -> Option<AVChannel> {
+        let raw = self.raw_channel(index)?;
+
+        if (AVChannel::AmbisonicBase as i32..=AVChannel::AmbisonicEnd as i32).contains(&raw) {
+            return Some(AVChannel::AmbisonicBase);
+        }
+
+        AVChannel::from_i32(raw)
+    }
+
+    /// The ambisonic channel number (ACN) of the channel at `index`, if it is
+    /// an ambisonic component.
+    pub fn ambisonic_component(&self, index: usize) -> Option<u32> {
+        let raw = self.raw_channel(index)?;
+        let base = AVChannel::AmbisonicBase as i32;
+
+        if (base..=AVChannel::AmbisonicEnd as i32).contains(&raw) {
+            Some((raw - base) as u32)
+        } else {
+            None
+        }
+    }
+
+    fn raw_channel(&self, index: usize) -> Option<i32> {
+        if index >= self.count() {
+            return None;
+        }
+
+        let raw = unsafe { sys::av_channel_layout_channel_from_index(&self.0, index as u32) };
+
+        if raw == AVChannel::None as i32 {
+            None
+        } else {
+            Some(raw)
+        }
+    }
+
+    /// The channel at each index, as returned by [`Self::channel`].
+    pub fn channels(&self) -> impl Iterator<Item = Option<AVChannel>> + '_ {
+        (0..self.count()).map(|i| self.channel(i))
+    }
+
+    /// The index of `channel` in this layout, if present.
+    pub fn index_of(&self, channel: AVChannel) -> Option<usize> {
+        let ret = unsafe { sys::av_channel_layout_index_from_channel(&self.0, channel as _) };
+
+        if ret < 0 { None } else { Some(ret as usize) }
+    }
+
+    /// The index of the channel with the given name (e.g. `"FL"`), if present.
+    pub fn index_of_name(&self, name: &str) -> Option<usize> {
+        let name = CString::new(name).ok()?;
+        let ret = unsafe { sys::av_channel_layout_index_from_string(&self.0, name.as_ptr()) };
+
+        if ret < 0 { None } else { Some(ret as usize) }
+    }
+
+    /// A human-readable description of the layout, such as `"stereo"` or
+    /// `"5.1(side)"`.
+    pub fn describe(&self) -> Result<String> {
+        let mut buffer = vec![0u8; 64];
+
+        loop {
+            let ret = unsafe {
+                sys::av_channel_layout_describe(
+                    &self.0,
+                    buffer.as_mut_ptr() as *mut c_char,
+                    buffer.len(),
+                )
+            };
+
+            if ret < 0 {
+                return Err(Error::new(ret));
+            }
+
+            // ret is the size needed including the nul terminator
+            let needed = ret as usize;
+            if needed > buffer.len() {
+                buffer.resize(needed, 0);
+                continue;
+            }
+
+            buffer.truncate(needed.saturating_sub(1));
+            return String::from_utf8(buffer).map_err(|_| Error::Utf8);
+        }
+    }
+}
+
+impl Clone for ChannelLayout {
+    /// # Panics
+    ///
+    /// Panics if the channel map of a custom-order layout cannot be
+    /// allocated, see [`ChannelLayout::try_clone`].
+    fn clone(&self) -> Self {
+        self.try_clone().expect("failed to copy the channel layout")
+    }
+}
+
+impl Drop for ChannelLayout {
+    fn drop(&mut self) {
+        unsafe { sys::av_channel_layout_uninit(&mut self.0) }
+    }
+}
+
+impl PartialEq for ChannelLayout {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { sys::av_channel_layout_compare(&self.0, &other.0) == 0 }
+    }
+}
+
+impl FromStr for ChannelLayout {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_string(s)
+    }
+}
+
+impl Display for ChannelLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = self.describe().map_err(|_| fmt::Error)?;
+        f.write_str(&description)
+    }
 }
 
 impl Debug for ChannelLayout {
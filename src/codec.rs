@@ -2,9 +2,12 @@ use crate::AVCodecId;
 use crate::AVDiscard;
 use crate::AVPixelFormat;
 use crate::AVSampleFormat;
+use crate::AVSubtitleType;
 use crate::ChannelLayout;
 use crate::Error;
 use crate::Rational;
+use crate::Subtitle;
+use crate::Timestamp;
 use crate::error::Result;
 use crate::frame::Frame;
 use crate::packet::Packet;
@@ -38,6 +41,14 @@ impl Codec {
         }
     }
 
+    pub(crate) fn from_raw(inner: *const sys::AVCodec) -> Option<Self> {
+        if inner.is_null() {
+            None
+        } else {
+            Some(Codec { inner })
+        }
+    }
+
     pub fn as_ptr(&self) -> *const sys::AVCodec {
         self.inner
     }
@@ -85,14 +96,16 @@ impl CodecContext {
         self.as_ref().height as usize
     }
 
-    /// The pixel format of the video.  
-    pub fn pixel_format(&self) -> AVPixelFormat {
-        AVPixelFormat::from_i32(self.as_ref().pix_fmt).unwrap()
+    /// The pixel format of the video, or `None` if it is not known to this
+    /// crate.
+    pub fn pixel_format(&self) -> Option<AVPixelFormat> {
+        AVPixelFormat::from_i32(self.as_ref().pix_fmt)
     }
 
-    /// The sample format of the audio.
-    pub fn sample_format(&self) -> AVSampleFormat {
-        AVSampleFormat::from_i32(self.as_ref().sample_fmt).unwrap()
+    /// The sample format of the audio, or `None` if it is not known to this
+    /// crate.
+    pub fn sample_format(&self) -> Option<AVSampleFormat> {
+        AVSampleFormat::from_i32(self.as_ref().sample_fmt)
     }
 
     /// The sample rate of the audio.
@@ -105,8 +118,8 @@ impl CodecContext {
         self.as_ref().ch_layout.nb_channels as usize
     }
 
-    pub fn channel_layout(&self) -> ChannelLayout {
-        ChannelLayout(self.as_ref().ch_layout)
+    pub fn channel_layout(&self) -> Result<ChannelLayout> {
+        ChannelLayout::from_raw(&self.as_ref().ch_layout)
     }
 
     /// The number of audio samples per channel in an audio frame.
@@ -149,6 +162,12 @@ impl CodecContext {
         self.as_ref().time_base.into()
     }
 
+    /// Wraps a raw timestamp in this context's time base, returning `None` if
+    /// it is unset.
+    pub fn timestamp(&self, value: i64) -> Option<Timestamp> {
+        Timestamp::from_raw(value, self.time_base())
+    }
+
     pub fn set_sample_rate(&mut self, sample_rate: i32) {
         self.as_mut().sample_rate = sample_rate;
     }
@@ -185,6 +204,114 @@ impl CodecContext {
             Ok(())
         }
     }
+
+    /// Decodes a subtitle packet. Returns `None` if the packet did not
+    /// complete a subtitle event.
+    pub fn decode_subtitle(&mut self, packet: &Packet) -> Result<Option<Subtitle>> {
+        let mut subtitle = Subtitle::zeroed();
+        let mut got_subtitle = 0;
+
+        let ret = unsafe {
+            sys::avcodec_decode_subtitle2(
+                self.as_mut(),
+                subtitle.as_mut_ptr(),
+                &mut got_subtitle,
+                packet.as_ref(),
+            )
+        };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else if got_subtitle == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(subtitle))
+        }
+    }
+
+    /// The output size limit that the ffmpeg cli uses for a single subtitle
+    /// event, for [`Self::encode_subtitle`].
+    pub const MAX_SUBTITLE_SIZE: usize = 1024 * 1024;
+
+    /// Encodes a subtitle into a packet of at most `max_size` bytes, using a
+    /// temporary buffer of that size.
+    ///
+    /// libavcodec's text encoders only take ASS rects, so subtitles with plain
+    /// text rects from [`Subtitle::add_text`] are rejected with `EINVAL`.
+    ///
+    /// The packet's timestamps are in `AV_TIME_BASE` units and its time base
+    /// is set accordingly, so it should be rescaled before muxing.
+    pub fn encode_subtitle(&mut self, subtitle: &Subtitle, max_size: usize) -> Result<Packet> {
+        if subtitle
+            .rects()
+            .any(|rect| rect.kind() == AVSubtitleType::Text)
+        {
+            return Err(Error::new(-libc::EINVAL));
+        }
+
+        let size = i32::try_from(max_size).map_err(|_| Error::new(-libc::EINVAL))?;
+        let mut buffer = vec![0u8; max_size];
+        let ret = unsafe {
+            sys::avcodec_encode_subtitle(
+                self.as_mut(),
+                buffer.as_mut_ptr(),
+                size,
+                subtitle.as_ptr(),
+            )
+        };
+
+        if ret < 0 {
+            return Err(Error::new(ret));
+        }
+
+        let mut packet = Packet::from_slice(&buffer[..ret as usize])?;
+
+        let start = subtitle.start_display_time().as_micros() as i64;
+        let end = subtitle.end_display_time().as_micros() as i64;
+
+        if let Some(pts) = subtitle.pts() {
+            packet.set_pts(pts + start);
+            packet.set_dts(pts + start);
+        }
+
+        packet.set_duration(end - start);
+        packet.set_time_base(Rational::new(1, sys::AV_TIME_BASE as i32));
+
+        Ok(packet)
+    }
+
+    /// The ASS header shared by all events of a subtitle stream, set by
+    /// subtitle decoders and required by subtitle encoders.
+    pub fn subtitle_header(&self) -> Option<&[u8]> {
+        let ctx = self.as_ref();
+
+        if ctx.subtitle_header.is_null() || ctx.subtitle_header_size <= 0 {
+            None
+        } else {
+            Some(unsafe {
+                std::slice::from_raw_parts(ctx.subtitle_header, ctx.subtitle_header_size as usize)
+            })
+        }
+    }
+
+    pub fn set_subtitle_header(&mut self, header: &[u8]) -> Result<()> {
+        unsafe {
+            // libav expects the header to be nul-terminated
+            let data = sys::av_mallocz(header.len() + 1) as *mut u8;
+            if data.is_null() {
+                return Err(Error::Alloc);
+            }
+
+            ptr::copy_nonoverlapping(header.as_ptr(), data, header.len());
+
+            let ctx = self.as_mut();
+            sys::av_freep(&mut ctx.subtitle_header as *mut _ as *mut _);
+            ctx.subtitle_header = data;
+            ctx.subtitle_header_size = header.len() as i32;
+        }
+
+        Ok(())
+    }
 }
 
 impl Drop for CodecContext {
@@ -0,0 +1,449 @@
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::ptr::{self, NonNull};
+
+use libavcodec_sys as sys;
+use num_traits::FromPrimitive;
+
+use crate::error::{Error, Result};
+use crate::{
+    AVChromaLocation, AVCodecId, AVColorPrimaries, AVColorRange, AVColorSpace,
+    AVColorTransferCharacteristic, AVFieldOrder, AVMediaType, AVPixelFormat, AVSampleFormat,
+    ChannelLayout, CodecContext, Rational,
+};
+
+/// The properties of an encoded stream, such as its codec, dimensions or
+/// sample rate.
+///
+/// This is only ever used by reference, borrowed from a
+/// [`Stream`](crate::Stream), a [`BitstreamFilter`](crate::BitstreamFilter)
+/// or an owned [`CodecParametersBuf`].
+#[repr(transparent)]
+pub struct CodecParameters(sys::AVCodecParameters);
+
+unsafe impl Send for CodecParameters {}
+unsafe impl Sync for CodecParameters {}
+
+impl CodecParameters {
+    /// # Safety
+    ///
+    /// `ptr` must point to valid parameters that are not mutated for `'a`.
+    pub(crate) unsafe fn from_raw<'a>(ptr: *const sys::AVCodecParameters) -> &'a Self {
+        unsafe { &*(ptr as *const Self) }
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must point to valid parameters that are not otherwise accessed
+    /// for `'a`.
+    pub(crate) unsafe fn from_raw_mut<'a>(ptr: *mut sys::AVCodecParameters) -> &'a mut Self {
+        unsafe { &mut *(ptr as *mut Self) }
+    }
+
+    pub fn as_ptr(&self) -> *const sys::AVCodecParameters {
+        &self.0
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut sys::AVCodecParameters {
+        &mut self.0
+    }
+
+    /// Overwrites these parameters with a copy of `other`.
+    pub fn copy_from(&mut self, other: &CodecParameters) -> Result<()> {
+        let ret = unsafe { sys::avcodec_parameters_copy(&mut self.0, &other.0) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Overwrites these parameters with those of a codec context, usually an
+    /// opened encoder.
+    pub fn copy_from_context(&mut self, codec_ctx: &CodecContext) -> Result<()> {
+        let ret = unsafe { sys::avcodec_parameters_from_context(&mut self.0, codec_ctx.as_ptr()) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Applies these parameters to a codec context, usually a decoder before
+    /// it is opened.
+    pub fn to_context(&self, codec_ctx: &mut CodecContext) -> Result<()> {
+        let ret = unsafe { sys::avcodec_parameters_to_context(codec_ctx.as_mut_ptr(), &self.0) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The media type, or `None` if it is not known to this crate.
+    pub fn codec_type(&self) -> Option<AVMediaType> {
+        AVMediaType::from_i32(self.0.codec_type)
+    }
+
+    pub fn set_codec_type(&mut self, codec_type: AVMediaType) {
+        self.0.codec_type = codec_type as _;
+    }
+
+    /// The codec, or `None` if it is not known to this crate.
+    pub fn codec_id(&self) -> Option<AVCodecId> {
+        AVCodecId::from_i64(self.0.codec_id as i64)
+    }
+
+    pub fn set_codec_id(&mut self, codec_id: AVCodecId) {
+        self.0.codec_id = codec_id as _;
+    }
+
+    /// The fourcc of the codec in the container, e.g. `avc1` for H.264 in
+    /// MP4, or 0 if unset.
+    pub fn codec_tag(&self) -> u32 {
+        self.0.codec_tag
+    }
+
+    pub fn set_codec_tag(&mut self, tag: u32) {
+        self.0.codec_tag = tag;
+    }
+
+    /// Codec specific setup data, such as H.264 SPS/PPS in `avcC` form.
+    pub fn extradata(&self) -> Option<&[u8]> {
+        if self.0.extradata.is_null() || self.0.extradata_size <= 0 {
+            None
+        } else {
+            Some(unsafe {
+                std::slice::from_raw_parts(self.0.extradata, self.0.extradata_size as usize)
+            })
+        }
+    }
+
+    pub fn set_extradata(&mut self, extradata: &[u8]) -> Result<()> {
+        let extradata_size =
+            i32::try_from(extradata.len()).map_err(|_| Error::new(-libc::EINVAL))?;
+
+        unsafe {
+            // libav reads the padding with optimized bitstream readers
+            let size = extradata.len() + sys::AV_INPUT_BUFFER_PADDING_SIZE as usize;
+            let data = sys::av_mallocz(size) as *mut u8;
+            if data.is_null() {
+                return Err(Error::Alloc);
+            }
+
+            ptr::copy_nonoverlapping(extradata.as_ptr(), data, extradata.len());
+
+            sys::av_freep(&mut self.0.extradata as *mut _ as *mut _);
+            self.0.extradata = data;
+            self.0.extradata_size = extradata_size;
+        }
+
+        Ok(())
+    }
+
+    /// The average bit rate in bits per second, or 0 if unknown.
+    pub fn bit_rate(&self) -> i64 {
+        self.0.bit_rate
+    }
+
+    pub fn set_bit_rate(&mut self, bit_rate: i64) {
+        self.0.bit_rate = bit_rate;
+    }
+
+    pub fn bits_per_coded_sample(&self) -> u32 {
+        self.0.bits_per_coded_sample.max(0) as u32
+    }
+
+    pub fn set_bits_per_coded_sample(&mut self, bits: u32) {
+        self.0.bits_per_coded_sample = bits as i32;
+    }
+
+    pub fn bits_per_raw_sample(&self) -> u32 {
+        self.0.bits_per_raw_sample.max(0) as u32
+    }
+
+    pub fn set_bits_per_raw_sample(&mut self, bits: u32) {
+        self.0.bits_per_raw_sample = bits as i32;
+    }
+
+    /// The codec specific profile, e.g. `AV_PROFILE_H264_HIGH`.
+    pub fn profile(&self) -> i32 {
+        self.0.profile
+    }
+
+    pub fn set_profile(&mut self, profile: i32) {
+        self.0.profile = profile;
+    }
+
+    pub fn level(&self) -> i32 {
+        self.0.level
+    }
+
+    pub fn set_level(&mut self, level: i32) {
+        self.0.level = level;
+    }
+
+    /// The pixel format of the video, or `None` if it is not known to this
+    /// crate.
+    pub fn pixel_format(&self) -> Option<AVPixelFormat> {
+        AVPixelFormat::from_i32(self.0.format)
+    }
+
+    pub fn set_pixel_format(&mut self, format: AVPixelFormat) {
+        self.0.format = format as i32;
+    }
+
+    /// The sample format of the audio, or `None` if it is not known to this
+    /// crate.
+    pub fn sample_format(&self) -> Option<AVSampleFormat> {
+        AVSampleFormat::from_i32(self.0.format)
+    }
+
+    pub fn set_sample_format(&mut self, format: AVSampleFormat) {
+        self.0.format = format as i32;
+    }
+
+    pub fn width(&self) -> usize {
+        self.0.width as usize
+    }
+
+    pub fn set_width(&mut self, width: usize) {
+        self.0.width = width as i32;
+    }
+
+    pub fn height(&self) -> usize {
+        self.0.height as usize
+    }
+
+    pub fn set_height(&mut self, height: usize) {
+        self.0.height = height as i32;
+    }
+
+    /// The shape of a pixel, or 0/1 if unknown.
+    pub fn sample_aspect_ratio(&self) -> Rational {
+        self.0.sample_aspect_ratio.into()
+    }
+
+    pub fn set_sample_aspect_ratio(&mut self, ratio: Rational) {
+        self.0.sample_aspect_ratio = ratio.into();
+    }
+
+    /// The frame rate of constant frame rate video, or 0/1 if unknown.
+    pub fn frame_rate(&self) -> Rational {
+        self.0.framerate.into()
+    }
+
+    pub fn set_frame_rate(&mut self, frame_rate: Rational) {
+        self.0.framerate = frame_rate.into();
+    }
+
+    pub fn field_order(&self) -> Option<AVFieldOrder> {
+        AVFieldOrder::from_i64(self.0.field_order as i64)
+    }
+
+    pub fn set_field_order(&mut self, field_order: AVFieldOrder) {
+        self.0.field_order = field_order as _;
+    }
+
+    pub fn color_range(&self) -> Option<AVColorRange> {
+        AVColorRange::from_i64(self.0.color_range as i64)
+    }
+
+    pub fn set_color_range(&mut self, range: AVColorRange) {
+        self.0.color_range = range as _;
+    }
+
+    pub fn color_primaries(&self) -> Option<AVColorPrimaries> {
+        AVColorPrimaries::from_i64(self.0.color_primaries as i64)
+    }
+
+    pub fn set_color_primaries(&mut self, primaries: AVColorPrimaries) {
+        self.0.color_primaries = primaries as _;
+    }
+
+    pub fn color_transfer_characteristic(&self) -> Option<AVColorTransferCharacteristic> {
+        AVColorTransferCharacteristic::from_i64(self.0.color_trc as i64)
+    }
+
+    pub fn set_color_transfer_characteristic(&mut self, trc: AVColorTransferCharacteristic) {
+        self.0.color_trc = trc as _;
+    }
+
+    pub fn color_space(&self) -> Option<AVColorSpace> {
+        AVColorSpace::from_i64(self.0.color_space as i64)
+    }
+
+    pub fn set_color_space(&mut self, space: AVColorSpace) {
+        self.0.color_space = space as _;
+    }
+
+    pub fn chroma_location(&self) -> Option<AVChromaLocation> {
+        AVChromaLocation::from_i64(self.0.chroma_location as i64)
+    }
+
+    pub fn set_chroma_location(&mut self, location: AVChromaLocation) {
+        self.0.chroma_location = location as _;
+    }
+
+    /// The number of frames the decoder delays its output by.
+    pub fn video_delay(&self) -> usize {
+        self.0.video_delay.max(0) as usize
+    }
+
+    pub fn set_video_delay(&mut self, delay: usize) {
+        self.0.video_delay = delay as i32;
+    }
+
+    /// The sample rate of the audio.
+    pub fn sample_rate(&self) -> usize {
+        self.0.sample_rate.max(0) as usize
+    }
+
+    pub fn set_sample_rate(&mut self, sample_rate: usize) {
+        self.0.sample_rate = sample_rate as i32;
+    }
+
+    /// The number of audio channels.
+    pub fn channel_count(&self) -> usize {
+        self.0.ch_layout.nb_channels.max(0) as usize
+    }
+
+    pub fn channel_layout(&self) -> Result<ChannelLayout> {
+        ChannelLayout::from_raw(&self.0.ch_layout)
+    }
+
+    pub fn set_channel_layout(&mut self, layout: ChannelLayout) -> Result<()> {
+        layout.copy_to(&mut self.0.ch_layout)
+    }
+
+    /// The size in bytes of an audio block, for codecs that use fixed-size
+    /// blocks such as PCM.
+    pub fn block_align(&self) -> usize {
+        self.0.block_align.max(0) as usize
+    }
+
+    pub fn set_block_align(&mut self, block_align: usize) {
+        self.0.block_align = block_align as i32;
+    }
+
+    /// The number of samples per channel in an audio frame, or 0 if it
+    /// varies.
+    pub fn frame_size(&self) -> usize {
+        self.0.frame_size.max(0) as usize
+    }
+
+    pub fn set_frame_size(&mut self, frame_size: usize) {
+        self.0.frame_size = frame_size as i32;
+    }
+
+    /// The number of priming samples the decoder output starts with, which
+    /// should be discarded.
+    pub fn initial_padding(&self) -> usize {
+        self.0.initial_padding.max(0) as usize
+    }
+
+    pub fn set_initial_padding(&mut self, samples: usize) {
+        self.0.initial_padding = samples as i32;
+    }
+
+    /// The number of padding samples at the end of the stream.
+    pub fn trailing_padding(&self) -> usize {
+        self.0.trailing_padding.max(0) as usize
+    }
+
+    pub fn set_trailing_padding(&mut self, samples: usize) {
+        self.0.trailing_padding = samples as i32;
+    }
+
+    /// The number of samples to decode and discard after seeking.
+    pub fn seek_preroll(&self) -> usize {
+        self.0.seek_preroll.max(0) as usize
+    }
+
+    pub fn set_seek_preroll(&mut self, samples: usize) {
+        self.0.seek_preroll = samples as i32;
+    }
+}
+
+impl fmt::Debug for CodecParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("CodecParameters");
+        s.field("codec_type", &self.codec_type())
+            .field("codec_id", &self.codec_id());
+
+        match self.codec_type() {
+            Some(AVMediaType::Video) => s
+                .field("width", &self.width())
+                .field("height", &self.height())
+                .field("pixel_format", &self.pixel_format()),
+            Some(AVMediaType::Audio) => s
+                .field("sample_rate", &self.sample_rate())
+                .field("channel_layout", &self.channel_layout().ok())
+                .field("sample_format", &self.sample_format()),
+            _ => &mut s,
+        };
+
+        s.finish()
+    }
+}
+
+/// Codec parameters owned by this crate rather than by a stream, e.g. to
+/// keep those of an input after it is closed.
+pub struct CodecParametersBuf {
+    inner: NonNull<sys::AVCodecParameters>,
+}
+
+unsafe impl Send for CodecParametersBuf {}
+
+impl CodecParametersBuf {
+    pub fn new() -> Result<Self> {
+        let inner = unsafe { sys::avcodec_parameters_alloc() };
+
+        Ok(CodecParametersBuf {
+            inner: NonNull::new(inner).ok_or(Error::Alloc)?,
+        })
+    }
+
+    /// Copies the parameters of a codec context, usually an opened encoder.
+    pub fn from_context(codec_ctx: &CodecContext) -> Result<Self> {
+        let mut params = Self::new()?;
+        params.copy_from_context(codec_ctx)?;
+        Ok(params)
+    }
+
+    /// Creates a copy of `params`.
+    pub fn from_parameters(params: &CodecParameters) -> Result<Self> {
+        let mut copy = Self::new()?;
+        copy.copy_from(params)?;
+        Ok(copy)
+    }
+}
+
+impl Deref for CodecParametersBuf {
+    type Target = CodecParameters;
+
+    fn deref(&self) -> &CodecParameters {
+        unsafe { CodecParameters::from_raw(self.inner.as_ptr()) }
+    }
+}
+
+impl DerefMut for CodecParametersBuf {
+    fn deref_mut(&mut self) -> &mut CodecParameters {
+        unsafe { CodecParameters::from_raw_mut(self.inner.as_ptr()) }
+    }
+}
+
+impl fmt::Debug for CodecParametersBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl Drop for CodecParametersBuf {
+    fn drop(&mut self) {
+        unsafe { sys::avcodec_parameters_free(&mut self.inner.as_ptr()) }
+    }
+}
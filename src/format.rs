@@ -1,7 +1,10 @@
 use crate::error::{Error, Result};
 use crate::io_context::{IoContext, ReadFn, SeekFn};
 use crate::packet::Packet;
-use crate::{AVError, IoContextParams, Stream};
+use crate::{
+    AVCodecId, AVDiscard, AVError, AVMediaType, Codec, Disposition, InputFormat, IoContextParams,
+    Program, ProgramMut, Stream, StreamMut,
+};
 use libavcodec_sys as sys;
 use std::ffi::CString;
 use std::fs::File;
@@ -15,6 +18,10 @@ pub struct FormatContext<D = ()> {
     // Keep IoContext alive as long as this FormatContext is alive
     // This is needed because the IoContext has callbacks that need to remain valid
     io_context: Option<IoContext<D>>,
+    // set once the header of an output has been written, until the trailer is
+    trailer_pending: bool,
+    // attached pictures to write right after the header
+    pending_cover_art: Vec<Packet>,
 }
 
 unsafe impl<D> Send for FormatContext<D> {}
@@ -33,16 +40,86 @@ impl<D> AsMut<sys::AVFormatContext> for FormatContext<D> {
 
 impl<D> FormatContext<D> {
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let path_str = path.as_ref().to_string_lossy();
-        let path_cstr = CString::new(path_str.as_bytes()).unwrap();
-        let mut inner = ptr::null_mut();
+        Self::open_with_format(path, None, &[])
+    }
+
+    /// Opens a file with a specific demuxer instead of probing for one, e.g.
+    /// for headerless formats like `s16le` or `rawvideo`.
+    ///
+    /// `options` are demuxer options, such as `("video_size", "1920x1080")`
+    /// for `rawvideo`. Options that the demuxer does not know are an error.
+    pub fn open_with_format<P: AsRef<Path>>(
+        path: P,
+        format: Option<InputFormat>,
+        options: &[(&str, &str)],
+    ) -> Result<Self> {
+        Self::alloc()?.open_input_with_format(Some(path.as_ref()), format, options)
+    }
+
+    pub unsafe fn from_raw(ptr: NonNull<sys::AVFormatContext>) -> Self {
+        Self {
+            inner: ptr,
+            io_context: None,
+            trailer_pending: false,
+            pending_cover_art: Vec::new(),
+        }
+    }
 
+    pub fn as_mut_ptr(&mut self) -> *mut sys::AVFormatContext {
+        self.inner.as_ptr()
+    }
+
+    pub fn as_ptr(&self) -> *const sys::AVFormatContext {
+        self.inner.as_ptr()
+    }
+
+    fn raw_streams(&self) -> &[*mut sys::AVStream] {
+        let nb_streams = self.as_ref().nb_streams as usize;
+
+        if nb_streams == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.as_ref().streams, nb_streams) }
+        }
+    }
+
+    pub fn streams(&self) -> impl Iterator<Item = Stream<'_>> {
+        self.raw_streams()
+            .iter()
+            .map(|&ptr| unsafe { Stream::from_raw(NonNull::new(ptr).unwrap()) })
+    }
+
+    /// The stream at `index`, which matches [`Packet::stream_index`].
+    pub fn stream(&self, index: usize) -> Option<Stream<'_>> {
+        let ptr = NonNull::new(*self.raw_streams().get(index)?)?;
+        Some(unsafe { Stream::from_raw(ptr) })
+    }
+
+    pub fn stream_mut(&mut self, index: usize) -> Option<StreamMut<'_>> {
+        let ptr = NonNull::new(*self.raw_streams().get(index)?)?;
+        Some(unsafe { StreamMut::from_raw(ptr) })
+    }
+
+    /// Picks the stream of `media_type` that players would choose, based on
+    /// its disposition, quality and the decoders available, and returns it
+    /// with its decoder. Attached pictures are not considered.
+    ///
+    /// If `related` is given, a stream of the same program as that stream is
+    /// preferred, e.g. the audio that belongs with a video.
+    pub fn best_stream(
+        &self,
+        media_type: AVMediaType,
+        related: Option<usize>,
+    ) -> Result<(Stream<'_>, Codec)> {
+        let mut decoder = ptr::null();
         let ret = unsafe {
-            sys::avformat_open_input(
-                &mut inner,
-                path_cstr.as_ptr(),
-                ptr::null_mut(),
-                ptr::null_mut(),
+            sys::av_find_best_stream(
+                self.as_ptr() as *mut _,
+                media_type as _,
+                -1,
+                related.map_or(-1, |index| index as i32),
+                &mut decoder,
+                0,
             )
         };
 
@@ -50,45 +127,88 @@ impl<D> FormatContext<D> {
             return Err(Error::new(ret));
         }
 
-        let ret = unsafe { sys::avformat_find_stream_info(inner, ptr::null_mut()) };
+        let stream = self
+            .stream(ret as usize)
+            .ok_or(Error::Av(AVError::StreamNotFound))?;
+        let decoder = Codec::from_raw(decoder).ok_or(Error::Av(AVError::DecoderNotFound))?;
 
-        if ret < 0 {
-            unsafe { sys::avformat_close_input(&mut inner) };
-            return Err(Error::new(ret));
-        }
+        Ok((stream, decoder))
+    }
 
-        let inner = NonNull::new(inner).unwrap();
+    /// The first attached picture of the input, e.g. the album art of an MP3
+    /// or M4A file, as an encoded image and its codec, usually
+    /// [`AVCodecId::Mjpeg`] or [`AVCodecId::Png`].
+    pub fn cover_art(&self) -> Result<Option<(Packet, AVCodecId)>> {
+        for stream in self.streams() {
+            let Some(codec_id) = stream.codec_id() else {
+                continue;
+            };
 
-        Ok(FormatContext {
-            inner,
-            io_context: None,
-        })
+            if let Some(packet) = stream.attached_pic()? {
+                return Ok(Some((packet, codec_id)));
+            }
+        }
+
+        Ok(None)
     }
 
-    pub unsafe fn from_raw(ptr: NonNull<sys::AVFormatContext>) -> Self {
-        Self {
-            inner: ptr,
-            io_context: None,
+    fn raw_programs(&self) -> &[*mut sys::AVProgram] {
+        let nb_programs = self.as_ref().nb_programs as usize;
+
+        if nb_programs == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.as_ref().programs, nb_programs) }
         }
     }
 
-    pub fn as_mut_ptr(&mut self) -> *mut sys::AVFormatContext {
-        self.inner.as_ptr()
+    /// The programs of the input, e.g. the TV channels of an MPEG-TS
+    /// multiplex. Most formats have none.
+    pub fn programs(&self) -> impl Iterator<Item = Program<'_>> {
+        self.raw_programs()
+            .iter()
+            .map(|&ptr| unsafe { Program::from_raw(NonNull::new(ptr).unwrap()) })
     }
 
-    pub fn as_ptr(&self) -> *const sys::AVFormatContext {
-        self.inner.as_ptr()
+    pub fn program(&self, index: usize) -> Option<Program<'_>> {
+        let ptr = NonNull::new(*self.raw_programs().get(index)?)?;
+        Some(unsafe { Program::from_raw(ptr) })
     }
 
-    pub fn streams(&self) -> impl Iterator<Item = Stream> {
-        let nb_streams = self.as_ref().nb_streams;
+    pub fn program_mut(&mut self, index: usize) -> Option<ProgramMut<'_>> {
+        let ptr = NonNull::new(*self.raw_programs().get(index)?)?;
+        Some(unsafe { ProgramMut::from_raw(ptr) })
+    }
+
+    /// Makes the demuxer skip every program except the one with id `id`, and
+    /// every stream that is not part of it. Returns `false` if there is no
+    /// such program.
+    pub fn select_program(&mut self, id: i32) -> bool {
+        let Some(selected) = self.programs().position(|program| program.id() == id) else {
+            return false;
+        };
 
-        let streams =
-            unsafe { std::slice::from_raw_parts(self.as_ref().streams, nb_streams as usize) };
+        let keep: Vec<usize> = self.program(selected).unwrap().stream_indices().collect();
 
-        streams.iter().map(|&ptr| Stream {
-            inner: NonNull::new(ptr).unwrap(),
-        })
+        for index in 0..self.raw_programs().len() {
+            let discard = if index == selected {
+                AVDiscard::Default
+            } else {
+                AVDiscard::All
+            };
+            self.program_mut(index).unwrap().set_discard(discard);
+        }
+
+        for index in 0..self.stream_count() {
+            let discard = if keep.contains(&index) {
+                AVDiscard::Default
+            } else {
+                AVDiscard::All
+            };
+            self.stream_mut(index).unwrap().set_discard(discard);
+        }
+
+        true
     }
 
     pub fn stream_count(&self) -> usize {
@@ -164,16 +284,24 @@ impl<D> FormatContext<D> {
         Ok(FormatContext {
             inner: ctx,
             io_context: None,
+            trailer_pending: false,
+            pending_cover_art: Vec::new(),
         })
     }
 
     pub fn write_header(&mut self) -> Result<()> {
         let ret = unsafe { sys::avformat_write_header(self.as_mut(), std::ptr::null_mut()) };
         if ret < 0 {
-            Err(Error::new(ret))
-        } else {
-            Ok(())
+            return Err(Error::new(ret));
         }
+
+        self.trailer_pending = true;
+
+        for mut packet in std::mem::take(&mut self.pending_cover_art) {
+            self.write_frame_interleaved(&mut packet)?;
+        }
+
+        Ok(())
     }
 
     pub fn write_frame(&mut self, packet: &mut Packet) -> Result<()> {
@@ -194,7 +322,11 @@ impl<D> FormatContext<D> {
         }
     }
 
+    /// Writes the trailer of an output. If this is not called, the trailer is
+    /// written when the context is dropped, with any error ignored.
     pub fn write_trailer(&mut self) -> Result<()> {
+        // av_write_trailer frees the muxer state even if it fails
+        self.trailer_pending = false;
         let ret = unsafe { sys::av_write_trailer(self.as_mut()) };
         if ret < 0 {
             Err(Error::new(ret))
@@ -203,11 +335,40 @@ impl<D> FormatContext<D> {
         }
     }
 
-    pub fn new_stream(&mut self) -> Result<Stream> {
+    pub fn new_stream(&mut self) -> Result<StreamMut<'_>> {
         let stream = unsafe { sys::avformat_new_stream(self.as_mut(), ptr::null()) };
         let stream = NonNull::new(stream).ok_or(Error::Alloc)?;
 
-        Ok(Stream { inner: stream })
+        Ok(unsafe { StreamMut::from_raw(stream) })
+    }
+
+    /// Adds a stream holding a cover image to an output, e.g. album art for
+    /// an MP3, M4A or FLAC file. `data` is the encoded image, usually JPEG or
+    /// PNG, and is written right after the header.
+    ///
+    /// Some muxers, like FLAC's, also record the image dimensions, which can
+    /// be set on the codec parameters of the returned stream.
+    pub fn add_cover_art(&mut self, codec_id: AVCodecId, data: &[u8]) -> Result<StreamMut<'_>> {
+        let mut packet = Packet::from_slice(data)?;
+        let mut stream = self.new_stream()?;
+
+        let codecpar = stream.codecpar_mut();
+        codecpar.set_codec_type(AVMediaType::Video);
+        codecpar.set_codec_id(codec_id);
+        stream.set_disposition(Disposition {
+            attached_pic: true,
+            ..Default::default()
+        });
+
+        packet.set_stream_index(stream.index());
+        packet.set_pts(0);
+        packet.set_dts(0);
+        packet.set_key(true);
+
+        let index = stream.index() as usize;
+        self.pending_cover_art.push(packet);
+
+        Ok(self.stream_mut(index).unwrap())
     }
 
     pub fn from_io(io: Io<D>) -> Result<Self> {
@@ -254,6 +415,8 @@ impl<D> FormatContext<D> {
             Ok(FormatContext {
                 inner: ptr,
                 io_context: None,
+                trailer_pending: false,
+                pending_cover_art: Vec::new(),
             })
         }
     }
@@ -275,60 +438,129 @@ impl<D> FormatContext<D> {
     /// This method is used to open an input after setting up a format context
     /// with a custom IoContext.
     pub fn open_input(self, file_name: Option<&Path>) -> Result<Self> {
+        self.open_input_with_format(file_name, None, &[])
+    }
+
+    /// Like [`open_input`](Self::open_input), but with a specific demuxer
+    /// and demuxer options, as in [`open_with_format`](Self::open_with_format).
+    pub fn open_input_with_format(
+        self,
+        file_name: Option<&Path>,
+        format: Option<InputFormat>,
+        options: &[(&str, &str)],
+    ) -> Result<Self> {
         unsafe {
             let file_name_cstr = match file_name {
                 Some(path) => {
                     let path_str = path.to_str().ok_or(Error::Utf8)?;
-                    let path_cstr = CString::new(path_str).unwrap();
+                    let path_cstr = CString::new(path_str).map_err(|_| Error::NulByte)?;
                     Some(path_cstr)
                 }
                 None => None,
             };
 
+            let mut dict = options_dictionary(options)?;
+
             // open input - we need to use a mutable pointer for avformat_open_input
             let mut ctx_ptr = self.inner.as_ptr();
             let ret = sys::avformat_open_input(
                 &mut ctx_ptr,
-                file_name_cstr.map_or(ptr::null_mut(), |s| s.as_ptr()),
-                ptr::null(),
-                ptr::null_mut(),
+                file_name_cstr.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+                format.map_or(ptr::null(), |f| f.as_ptr()),
+                &mut dict,
             );
 
+            // avformat_open_input leaves the options it did not use
+            let unused_options = sys::av_dict_count(dict);
+            sys::av_dict_free(&mut dict);
+
             if ret < 0 {
                 // this method consumes self b/c if avformat_open_input fails,
                 // it frees the context!
+                self.forget_freed();
                 return Err(Error::new(ret));
             }
 
+            if unused_options > 0 {
+                sys::avformat_close_input(&mut ctx_ptr);
+                self.forget_freed();
+                return Err(Error::Av(AVError::OptionNotFound));
+            }
+
             let ret = sys::avformat_find_stream_info(ctx_ptr, ptr::null_mut());
 
             if ret < 0 {
                 sys::avformat_close_input(&mut ctx_ptr);
+                self.forget_freed();
                 return Err(Error::new(ret));
             }
 
             Ok(self)
         }
     }
+
+    /// The demuxer of an opened input, whether probed or forced.
+    pub fn input_format(&self) -> Option<InputFormat> {
+        InputFormat::from_raw(self.as_ref().iformat)
+    }
+
+    // drops everything but the context itself, which libav already freed
+    fn forget_freed(mut self) {
+        drop(self.io_context.take());
+        std::mem::forget(self);
+    }
+}
+
+fn options_dictionary(options: &[(&str, &str)]) -> Result<*mut sys::AVDictionary> {
+    let mut dict = ptr::null_mut();
+
+    for (key, value) in options {
+        let (Ok(key), Ok(value)) = (CString::new(*key), CString::new(*value)) else {
+            unsafe { sys::av_dict_free(&mut dict) };
+            return Err(Error::NulByte);
+        };
+
+        let ret = unsafe { sys::av_dict_set(&mut dict, key.as_ptr(), value.as_ptr(), 0) };
+
+        if ret < 0 {
+            unsafe { sys::av_dict_free(&mut dict) };
+            return Err(Error::new(ret));
+        }
+    }
+
+    Ok(dict)
 }
 
 impl<D> Drop for FormatContext<D> {
     fn drop(&mut self) {
         unsafe {
-            // sys::avformat_close_input(&mut self.inner);
-
-            // if let Some(fmt) = self.as_ref().oformat.as_ref() {
-            //     if (fmt.flags & sys::AVFMT_NOFILE as i32) == 0 {
-            //         let mut pb = self.as_mut().pb;
-            //         if !pb.is_null() {
-            //             // Only close pb if we don't have an _io_context
-            //             // If we have an _io_context, its Drop impl will handle this
-            //             if self.io_context.is_none() {
-            //                 sys::avio_closep(&mut pb);
-            //             }
-            //         }
-            //     }
-            // }
+            if !self.as_ref().iformat.is_null() {
+                // closes the input file too, unless it uses custom io
+                let mut ptr = self.as_mut_ptr();
+                sys::avformat_close_input(&mut ptr);
+                return;
+            }
+
+            if self.trailer_pending {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("output dropped without writing the trailer, writing it now");
+
+                let _ = self.write_trailer();
+            }
+
+            if let Some(fmt) = self.as_ref().oformat.as_ref() {
+                let custom_io = self.io_context.is_some()
+                    || (self.as_ref().flags & sys::AVFMT_FLAG_CUSTOM_IO as i32) != 0;
+
+                if (fmt.flags & sys::AVFMT_NOFILE as i32) == 0 && !self.as_ref().pb.is_null() {
+                    if custom_io {
+                        // the IoContext frees itself when dropped after this
+                        sys::avio_flush(self.as_mut().pb);
+                    } else {
+                        sys::avio_closep(&mut self.as_mut().pb);
+                    }
+                }
+            }
 
             sys::avformat_free_context(self.as_mut_ptr());
         }
@@ -1,5 +1,9 @@
-use crate::{error::Result, AVPixelFormat, AVSampleFormat, ChannelLayout, Error};
+use crate::{
+    AVFrameSideDataType, AVPixelFormat, AVSampleFormat, ChannelLayout, Error, FrameSideData,
+    Rational, Sample, SideData, Timestamp, error::Result,
+};
 use libavcodec_sys as sys;
+use num_traits::FromPrimitive;
 use std::{ptr::NonNull, slice};
 
 pub struct Frame {
@@ -123,7 +127,7 @@ impl Frame {
             self.inner_mut().nb_samples = sample_count as i32;
             self.inner_mut().format = sample_fmt as i32;
             self.inner_mut().sample_rate = sample_rate;
-            self.inner_mut().ch_layout = channel_layout.0;
+            channel_layout.copy_to(&mut self.inner_mut().ch_layout)?;
 
             let mut linesize = 0;
             let size = sys::av_samples_get_buffer_size(
@@ -233,6 +237,56 @@ impl Frame {
         }
     }
 
+    /// The samples of an audio plane: every channel interleaved for packed
+    /// formats, or a single channel for planar formats. Returns `None` if
+    /// the plane does not exist or `T` is not the frame's sample type.
+    pub fn samples<T: Sample>(&self, plane: usize) -> Option<&[T]> {
+        let (data, len) = self.sample_plane::<T>(plane)?;
+        Some(unsafe { slice::from_raw_parts(data as *const T, len) })
+    }
+
+    /// Like [`Self::samples`], but makes the frame writable first if its
+    /// buffers are shared with other frames.
+    pub fn samples_mut<T: Sample>(&mut self, plane: usize) -> Result<Option<&mut [T]>> {
+        if self.sample_plane::<T>(plane).is_none() {
+            return Ok(None);
+        }
+
+        // this may copy the data into new buffers, so look the plane up again
+        self.make_writable()?;
+
+        Ok(self
+            .sample_plane::<T>(plane)
+            .map(|(data, len)| unsafe { slice::from_raw_parts_mut(data as *mut T, len) }))
+    }
+
+    // the data pointer and number of samples in an audio plane
+    fn sample_plane<T: Sample>(&self, plane: usize) -> Option<(*mut u8, usize)> {
+        let format = AVSampleFormat::from_i32(self.format())?;
+        if !T::is_format(format) {
+            return None;
+        }
+
+        let channels = self.channel_count().max(0) as usize;
+        let samples = self.sample_count().max(0) as usize;
+        let (planes, len) = if format.is_planar() {
+            (channels, samples)
+        } else {
+            (1, samples * channels)
+        };
+
+        if plane >= planes || self.inner().extended_data.is_null() {
+            return None;
+        }
+
+        let data = unsafe { *self.inner().extended_data.add(plane) };
+        if data.is_null() {
+            None
+        } else {
+            Some((data, len))
+        }
+    }
+
     pub unsafe fn data_ptrs(&self) -> &[*mut u8] {
         &self.inner().data
     }
@@ -269,6 +323,16 @@ impl Frame {
         self.inner().best_effort_timestamp
     }
 
+    /// The presentation timestamp in the given time base, if set.
+    pub fn pts_at(&self, time_base: Rational) -> Option<Timestamp> {
+        Timestamp::from_raw(self.pts(), time_base)
+    }
+
+    /// The best effort timestamp in the given time base, if set.
+    pub fn best_effort_timestamp_at(&self, time_base: Rational) -> Option<Timestamp> {
+        Timestamp::from_raw(self.best_effort_timestamp(), time_base)
+    }
+
     pub fn width(&self) -> i32 {
         self.inner().width
     }
@@ -289,8 +353,8 @@ impl Frame {
         self.inner().ch_layout.nb_channels
     }
 
-    pub fn channel_layout(&self) -> ChannelLayout {
-        ChannelLayout(self.inner().ch_layout)
+    pub fn channel_layout(&self) -> Result<ChannelLayout> {
+        ChannelLayout::from_raw(&self.inner().ch_layout)
     }
 
     pub fn set_pts(&mut self, pts: i64) {
@@ -309,8 +373,8 @@ impl Frame {
         self.inner_mut().sample_rate = rate;
     }
 
-    pub fn set_channel_layout(&mut self, layout: ChannelLayout) {
-        self.inner_mut().ch_layout = layout.0;
+    pub fn set_channel_layout(&mut self, layout: ChannelLayout) -> Result<()> {
+        layout.copy_to(&mut self.inner_mut().ch_layout)
     }
 
     pub fn set_format(&mut self, format: i32) {
@@ -339,6 +403,56 @@ impl Frame {
         }
     }
 
+    /// Iterates over all side data attached to this frame.
+    pub fn side_data(&self) -> impl Iterator<Item = FrameSideData<'_>> {
+        let inner = self.inner();
+        let entries = if inner.side_data.is_null() {
+            &[][..]
+        } else {
+            unsafe { slice::from_raw_parts(inner.side_data, inner.nb_side_data as usize) }
+        };
+
+        entries
+            .iter()
+            .filter_map(|&ptr| unsafe { ptr.as_ref() })
+            .map(FrameSideData::new)
+    }
+
+    /// Returns the side data of the given type, if present.
+    pub fn side_data_of(&self, kind: AVFrameSideDataType) -> Option<FrameSideData<'_>> {
+        let ptr = unsafe { sys::av_frame_get_side_data(self.as_ptr(), kind as _) };
+        unsafe { ptr.as_ref() }.map(FrameSideData::new)
+    }
+
+    /// Returns the side data of type `T` parsed into its typed form, if
+    /// present.
+    pub fn get_side_data<T: SideData>(&self) -> Option<T> {
+        self.side_data_of(T::FRAME_KIND?)?.parse()
+    }
+
+    /// Attaches a copy of `data` as side data of the given type.
+    pub fn add_side_data_raw(&mut self, kind: AVFrameSideDataType, data: &[u8]) -> Result<()> {
+        unsafe {
+            let sd = sys::av_frame_new_side_data(self.inner_mut(), kind as _, data.len() as _);
+            let sd = sd.as_mut().ok_or(Error::Alloc)?;
+            std::ptr::copy_nonoverlapping(data.as_ptr(), sd.data, data.len());
+        }
+
+        Ok(())
+    }
+
+    /// Attaches typed side data to this frame. Fails with `EINVAL` if `T`
+    /// cannot be carried by frames.
+    pub fn add_side_data<T: SideData>(&mut self, value: &T) -> Result<()> {
+        let kind = T::FRAME_KIND.ok_or(Error::new(-libc::EINVAL))?;
+        self.add_side_data_raw(kind, &value.to_bytes())
+    }
+
+    /// Removes all side data of the given type.
+    pub fn remove_side_data(&mut self, kind: AVFrameSideDataType) {
+        unsafe { sys::av_frame_remove_side_data(self.inner_mut(), kind as _) }
+    }
+
     pub fn samples_linesize(&self, align: i32) -> Result<i32> {
         let mut linesize = 0;
         let ret = unsafe {
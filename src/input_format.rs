@@ -0,0 +1,130 @@
+use std::ffi::{CStr, CString, c_char};
+use std::fmt;
+use std::path::Path;
+use std::ptr;
+
+use libavcodec_sys as sys;
+
+/// A demuxer, such as `mov` or `rawvideo`.
+#[derive(Clone, Copy)]
+pub struct InputFormat {
+    inner: &'static sys::AVInputFormat,
+}
+
+unsafe impl Send for InputFormat {}
+unsafe impl Sync for InputFormat {}
+
+impl InputFormat {
+    pub(crate) fn from_raw(ptr: *const sys::AVInputFormat) -> Option<Self> {
+        Some(InputFormat {
+            inner: unsafe { ptr.as_ref()? },
+        })
+    }
+
+    /// Looks up a demuxer by its short name, e.g. `"s16le"` or `"h264"`.
+    pub fn find(name: &str) -> Option<Self> {
+        let name = CString::new(name).ok()?;
+        Self::from_raw(unsafe { sys::av_find_input_format(name.as_ptr()) })
+    }
+
+    /// Guesses the format of the start of a file, e.g. read from a socket
+    /// before opening it. `file_name` helps with formats that are hard to
+    /// tell apart by content alone.
+    ///
+    /// Returns the format along with a score of how sure libav is, from 1 to
+    /// [`InputFormat::MAX_SCORE`]. Formats detected by extension alone score
+    /// [`InputFormat::EXTENSION_SCORE`].
+    pub fn probe(data: &[u8], file_name: Option<&Path>) -> Option<(Self, u32)> {
+        // the probe functions may read past the end, so pad with zeros
+        let mut buf = Vec::with_capacity(data.len() + sys::AVPROBE_PADDING_SIZE as usize);
+        buf.extend_from_slice(data);
+        buf.resize(data.len() + sys::AVPROBE_PADDING_SIZE as usize, 0);
+
+        let file_name = file_name
+            .and_then(|path| path.to_str())
+            .and_then(|path| CString::new(path).ok());
+
+        let probe_data = sys::AVProbeData {
+            filename: file_name
+                .as_ref()
+                .map_or(c"".as_ptr(), |name| name.as_ptr()),
+            buf: buf.as_mut_ptr(),
+            buf_size: i32::try_from(data.len()).ok()?,
+            mime_type: ptr::null(),
+        };
+
+        let mut score = 0;
+        let format = unsafe { sys::av_probe_input_format3(&probe_data, 1, &mut score) };
+
+        Some((Self::from_raw(format)?, score.max(0) as u32))
+    }
+
+    /// The highest probe score, for a certain match.
+    pub const MAX_SCORE: u32 = sys::AVPROBE_SCORE_MAX;
+
+    /// The probe score of a match by file extension.
+    pub const EXTENSION_SCORE: u32 = sys::AVPROBE_SCORE_EXTENSION;
+
+    pub fn as_ptr(&self) -> *const sys::AVInputFormat {
+        self.inner
+    }
+
+    /// The short name of the format, e.g. `"mov,mp4,m4a,3gp,3g2,mj2"`. Some
+    /// demuxers handle several formats and list all of their names.
+    pub fn name(&self) -> &'static str {
+        cstr(self.inner.name).unwrap_or("")
+    }
+
+    /// A human readable name, e.g. `"QuickTime / MOV"`.
+    pub fn long_name(&self) -> Option<&'static str> {
+        cstr(self.inner.long_name)
+    }
+
+    /// The file extensions of the format, without dots.
+    pub fn extensions(&self) -> impl Iterator<Item = &'static str> {
+        split_list(self.inner.extensions)
+    }
+
+    /// The MIME types of the format, e.g. `"audio/mpeg"`.
+    pub fn mime_types(&self) -> impl Iterator<Item = &'static str> {
+        split_list(self.inner.mime_type)
+    }
+
+    /// Whether the format has no file of its own, e.g. a capture device or
+    /// an image sequence.
+    pub fn is_no_file(&self) -> bool {
+        (self.inner.flags & sys::AVFMT_NOFILE as i32) != 0
+    }
+}
+
+fn cstr(ptr: *const c_char) -> Option<&'static str> {
+    if ptr.is_null() {
+        None
+    } else {
+        unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+    }
+}
+
+fn split_list(ptr: *const c_char) -> impl Iterator<Item = &'static str> {
+    cstr(ptr)
+        .unwrap_or("")
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+impl PartialEq for InputFormat {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.inner, other.inner)
+    }
+}
+
+impl Eq for InputFormat {}
+
+impl fmt::Debug for InputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InputFormat")
+            .field("name", &self.name())
+            .finish()
+    }
+}
@@ -1,27 +1,55 @@
+#[cfg(feature = "rayon")]
+mod band_scaler;
+mod bsf;
 mod channel_layout;
 mod codec;
+mod codec_parameters;
 mod constants;
 mod error;
 mod format;
 mod frame;
+mod input_format;
 mod io_context;
 mod packet;
+mod parser;
+mod pixel_format;
+mod program;
 mod rational;
+mod raw;
+mod resampler;
+mod sample_format;
+mod scaler;
+mod side_data;
 mod stream;
+mod subtitle;
 mod swr;
 mod sws;
 
+#[cfg(feature = "rayon")]
+pub use band_scaler::*;
+pub use bsf::*;
 pub use channel_layout::*;
 pub use codec::*;
+pub use codec_parameters::*;
 pub use constants::*;
 pub use error::*;
 pub use format::*;
 pub use frame::*;
+pub use input_format::*;
 pub use io_context::{IoContext, IoContextParams};
 pub(crate) use libavcodec_sys as sys;
 pub use packet::*;
+pub use parser::*;
+pub use pixel_format::*;
+pub use program::*;
 pub use rational::*;
+pub use raw::*;
+pub use resampler::*;
+pub use sample_format::*;
+pub use scaler::*;
+pub use side_data::*;
 pub use stream::*;
+pub use subtitle::*;
 pub use swr::*;
 pub use sws::*;
 
@@ -29,8 +57,6 @@ pub use sws::*;
 mod tracing_support {
     use std::ffi::{CStr, c_void};
 
-    use num_traits::FromPrimitive;
-
     use crate::AVLogLevel;
 
     use super::sys;
@@ -41,16 +67,14 @@ mod tracing_support {
         fmt: *const i8,
         args: *mut c_void,
     ) {
-        let level = AVLogLevel::from_i32(level).unwrap();
+        // levels between the named ones are rounded to the next less
+        // severe one
         let level = match level {
-            AVLogLevel::Panic => tracing::Level::ERROR,
-            AVLogLevel::Fatal => tracing::Level::ERROR,
-            AVLogLevel::Error => tracing::Level::ERROR,
-            AVLogLevel::Warning => tracing::Level::WARN,
-            AVLogLevel::Info => tracing::Level::INFO,
-            AVLogLevel::Verbose => tracing::Level::DEBUG,
-            AVLogLevel::Debug => tracing::Level::DEBUG,
-            AVLogLevel::Trace => tracing::Level::TRACE,
+            l if l <= AVLogLevel::Error as i32 => tracing::Level::ERROR,
+            l if l <= AVLogLevel::Warning as i32 => tracing::Level::WARN,
+            l if l <= AVLogLevel::Info as i32 => tracing::Level::INFO,
+            l if l <= AVLogLevel::Debug as i32 => tracing::Level::DEBUG,
+            _ => tracing::Level::TRACE,
         };
 
         let mut buffer = [0; 1024];
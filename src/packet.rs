@@ -1,6 +1,10 @@
-use std::ptr::NonNull;
+use std::ffi::c_void;
+use std::mem::ManuallyDrop;
+use std::ptr::{self, NonNull};
+use std::slice;
 
 use crate::error::{Error, Result};
+use crate::{AVPacketSideDataType, PacketSideData, Rational, SideData, Timestamp};
 use libavcodec_sys as sys;
 
 #[derive(Debug)]
@@ -30,6 +34,83 @@ impl Packet {
         Ok(Packet { inner })
     }
 
+    /// Creates a packet holding a copy of `data`.
+    pub fn from_slice(data: &[u8]) -> Result<Self> {
+        let size = i32::try_from(data.len()).map_err(|_| Error::new(-libc::EINVAL))?;
+        let mut packet = Self::new()?;
+
+        unsafe {
+            let ret = sys::av_new_packet(packet.as_mut(), size);
+            if ret < 0 {
+                return Err(Error::new(ret));
+            }
+
+            ptr::copy_nonoverlapping(data.as_ptr(), packet.as_mut().data, data.len());
+        }
+
+        Ok(packet)
+    }
+
+    /// Creates a packet that takes ownership of `data` without copying it.
+    ///
+    /// libav requires packet data to be followed by zeroed padding, so the
+    /// vector is extended by `AV_INPUT_BUFFER_PADDING_SIZE` bytes first. This
+    /// only reallocates if the vector does not already have that much spare
+    /// capacity.
+    pub fn from_vec(mut data: Vec<u8>) -> Result<Self> {
+        let size = data.len();
+        let size_i32 = i32::try_from(size).map_err(|_| Error::new(-libc::EINVAL))?;
+        let mut packet = Self::new()?;
+
+        data.resize(size + sys::AV_INPUT_BUFFER_PADDING_SIZE as usize, 0);
+        let mut data = ManuallyDrop::new(data);
+        let data_ptr = data.as_mut_ptr();
+        let capacity = data.capacity();
+
+        unsafe {
+            // the buffer's free callback reconstructs the vector, so we
+            // smuggle its capacity through the opaque pointer
+            let buf = sys::av_buffer_create(
+                data_ptr,
+                size as _,
+                Some(free_vec),
+                capacity as *mut c_void,
+                0,
+            );
+
+            if buf.is_null() {
+                ManuallyDrop::drop(&mut data);
+                return Err(Error::Alloc);
+            }
+
+            let inner = packet.as_mut();
+            inner.buf = buf;
+            inner.data = data_ptr;
+            inner.size = size_i32;
+        }
+
+        Ok(packet)
+    }
+
+    /// Creates a new reference to the same data as this packet, along with a
+    /// copy of its properties.
+    pub fn try_clone(&self) -> Result<Self> {
+        Self::from_raw_ref(self.as_ref())
+    }
+
+    /// Creates a new reference to a packet owned by libav, copying its data
+    /// if it is not reference counted.
+    pub(crate) fn from_raw_ref(raw: &sys::AVPacket) -> Result<Self> {
+        let mut packet = Self::new()?;
+        let ret = unsafe { sys::av_packet_ref(packet.as_mut(), raw) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(packet)
+        }
+    }
+
     pub fn stream_index(&self) -> i32 {
         self.as_ref().stream_index
     }
@@ -54,9 +135,19 @@ impl Packet {
         self.as_mut().stream_index = index
     }
 
-    pub fn rescale_ts(&mut self, src_tb: sys::AVRational, dst_tb: sys::AVRational) {
+    /// The presentation timestamp in the given time base, if set.
+    pub fn pts_at(&self, time_base: Rational) -> Option<Timestamp> {
+        Timestamp::from_raw(self.pts(), time_base)
+    }
+
+    /// The decoding timestamp in the given time base, if set.
+    pub fn dts_at(&self, time_base: Rational) -> Option<Timestamp> {
+        Timestamp::from_raw(self.dts(), time_base)
+    }
+
+    pub fn rescale_ts(&mut self, src_tb: Rational, dst_tb: Rational) {
         unsafe {
-            sys::av_packet_rescale_ts(self.as_mut(), src_tb, dst_tb);
+            sys::av_packet_rescale_ts(self.as_mut(), src_tb.into(), dst_tb.into());
         }
     }
 
@@ -72,6 +163,40 @@ impl Packet {
         self.as_ref().size
     }
 
+    /// The packet payload, or an empty slice if the packet holds no data.
+    pub fn as_slice(&self) -> &[u8] {
+        let inner = self.as_ref();
+
+        if inner.data.is_null() || inner.size <= 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(inner.data, inner.size as usize) }
+        }
+    }
+
+    /// The packet payload, made writable first if it is shared with other
+    /// packets.
+    pub fn as_mut_slice(&mut self) -> Result<&mut [u8]> {
+        self.make_writable()?;
+
+        let inner = self.as_mut();
+
+        if inner.data.is_null() || inner.size <= 0 {
+            Ok(&mut [])
+        } else {
+            Ok(unsafe { slice::from_raw_parts_mut(inner.data, inner.size as usize) })
+        }
+    }
+
+    pub fn make_writable(&mut self) -> Result<()> {
+        let ret = unsafe { sys::av_packet_make_writable(self.as_mut()) };
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn data(&self) -> *const u8 {
         self.as_ref().data
     }
@@ -80,11 +205,120 @@ impl Packet {
         self.as_mut().data
     }
 
+    /// Byte position of the packet in the stream, if known.
+    pub fn pos(&self) -> Option<i64> {
+        let pos = self.as_ref().pos;
+        if pos < 0 { None } else { Some(pos) }
+    }
+
+    pub fn set_pos(&mut self, pos: Option<i64>) {
+        self.as_mut().pos = pos.unwrap_or(-1)
+    }
+
+    /// The time base of the packet's timestamps. This is only set by some
+    /// APIs and may be 0/1 otherwise.
+    pub fn time_base(&self) -> Rational {
+        self.as_ref().time_base.into()
+    }
+
+    pub fn set_time_base(&mut self, time_base: Rational) {
+        self.as_mut().time_base = time_base.into()
+    }
+
+    fn has_flag(&self, flag: u32) -> bool {
+        self.as_ref().flags & flag as i32 != 0
+    }
+
+    fn set_flag(&mut self, flag: u32, value: bool) {
+        if value {
+            self.as_mut().flags |= flag as i32;
+        } else {
+            self.as_mut().flags &= !(flag as i32);
+        }
+    }
+
+    /// Whether the packet contains a keyframe.
+    pub fn is_key(&self) -> bool {
+        self.has_flag(sys::AV_PKT_FLAG_KEY)
+    }
+
+    pub fn set_key(&mut self, value: bool) {
+        self.set_flag(sys::AV_PKT_FLAG_KEY, value)
+    }
+
+    /// Whether the packet content is corrupted.
+    pub fn is_corrupt(&self) -> bool {
+        self.has_flag(sys::AV_PKT_FLAG_CORRUPT)
+    }
+
+    pub fn set_corrupt(&mut self, value: bool) {
+        self.set_flag(sys::AV_PKT_FLAG_CORRUPT, value)
+    }
+
+    /// Whether the packet is needed to maintain valid decoder state but
+    /// should be discarded after decoding.
+    pub fn is_discard(&self) -> bool {
+        self.has_flag(sys::AV_PKT_FLAG_DISCARD)
+    }
+
+    pub fn set_discard(&mut self, value: bool) {
+        self.set_flag(sys::AV_PKT_FLAG_DISCARD, value)
+    }
+
+    /// Iterates over all side data attached to this packet.
+    pub fn side_data(&self) -> impl Iterator<Item = PacketSideData<'_>> {
+        let inner = self.as_ref();
+        let entries = if inner.side_data.is_null() {
+            &[][..]
+        } else {
+            unsafe { slice::from_raw_parts(inner.side_data, inner.side_data_elems as usize) }
+        };
+
+        entries.iter().map(PacketSideData::new)
+    }
+
+    /// Returns the side data of the given type, if present.
+    pub fn side_data_of(&self, kind: AVPacketSideDataType) -> Option<PacketSideData<'_>> {
+        self.side_data().find(|sd| sd.kind() == Some(kind))
+    }
+
+    /// Returns the side data of type `T` parsed into its typed form, if
+    /// present.
+    pub fn get_side_data<T: SideData>(&self) -> Option<T> {
+        self.side_data_of(T::PACKET_KIND?)?.parse()
+    }
+
+    /// Attaches a copy of `data` as side data of the given type.
+    pub fn add_side_data_raw(&mut self, kind: AVPacketSideDataType, data: &[u8]) -> Result<()> {
+        unsafe {
+            let ptr = sys::av_packet_new_side_data(self.as_mut(), kind as _, data.len() as _);
+            if ptr.is_null() {
+                return Err(Error::Alloc);
+            }
+
+            std::ptr::copy_nonoverlapping(data.as_ptr(), ptr, data.len());
+        }
+
+        Ok(())
+    }
+
+    /// Attaches typed side data to this packet. Fails with `EINVAL` if `T`
+    /// cannot be carried by packets.
+    pub fn add_side_data<T: SideData>(&mut self, value: &T) -> Result<()> {
+        let kind = T::PACKET_KIND.ok_or(Error::new(-libc::EINVAL))?;
+        self.add_side_data_raw(kind, &value.to_bytes())
+    }
+
     pub fn unref(&mut self) {
         unsafe { sys::av_packet_unref(self.inner.as_ptr()) }
     }
 }
 
+unsafe extern "C" fn free_vec(opaque: *mut c_void, data: *mut u8) {
+    let capacity = opaque as usize;
+    drop(unsafe { Vec::from_raw_parts(data, 0, capacity) });
+}
+
 impl Drop for Packet {
     fn drop(&mut self) {
         unsafe {
@@ -0,0 +1,165 @@
+use std::ptr::{self, NonNull};
+
+use libavcodec_sys as sys;
+
+use crate::error::{Error, Result};
+use crate::{AVCodecId, CodecContext, NOPTS_VALUE, Packet};
+
+/// Splits a raw elementary stream (e.g. Annex B H.264, ADTS AAC or MP3) into
+/// packets that can be sent to a decoder.
+pub struct Parser {
+    inner: NonNull<sys::AVCodecParserContext>,
+    // the input copied with the zeroed padding that parsers may read into
+    buffer: Vec<u8>,
+}
+
+unsafe impl Send for Parser {}
+
+impl AsRef<sys::AVCodecParserContext> for Parser {
+    fn as_ref(&self) -> &sys::AVCodecParserContext {
+        unsafe { self.inner.as_ref() }
+    }
+}
+
+impl AsMut<sys::AVCodecParserContext> for Parser {
+    fn as_mut(&mut self) -> &mut sys::AVCodecParserContext {
+        unsafe { self.inner.as_mut() }
+    }
+}
+
+impl Parser {
+    /// Creates a parser for the given codec. Returns `None` if libavcodec has
+    /// no parser for it.
+    pub fn new(codec_id: AVCodecId) -> Option<Self> {
+        let inner = unsafe { sys::av_parser_init(codec_id as _) };
+        NonNull::new(inner).map(|inner| Parser {
+            inner,
+            buffer: Vec::new(),
+        })
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut sys::AVCodecParserContext {
+        self.inner.as_ptr()
+    }
+
+    pub fn as_ptr(&self) -> *const sys::AVCodecParserContext {
+        self.inner.as_ptr()
+    }
+
+    /// Feeds `data` to the parser and returns the number of bytes consumed,
+    /// along with a packet if one was completed.
+    ///
+    /// `pts`, `dts` and `pos` describe the start of `data` and are propagated
+    /// to the packet that contains it. Pass an empty slice to flush the
+    /// parser at the end of the stream.
+    ///
+    /// `data` is copied into a padded buffer first, so it needs no padding of
+    /// its own.
+    pub fn parse(
+        &mut self,
+        codec_ctx: &mut CodecContext,
+        data: &[u8],
+        pts: Option<i64>,
+        dts: Option<i64>,
+        pos: Option<i64>,
+    ) -> Result<(usize, Option<Packet>)> {
+        let size = i32::try_from(data.len()).map_err(|_| Error::new(-libc::EINVAL))?;
+        let mut out_data = ptr::null_mut();
+        let mut out_size = 0;
+
+        self.buffer.clear();
+        self.buffer.extend_from_slice(data);
+        self.buffer
+            .resize(data.len() + sys::AV_INPUT_BUFFER_PADDING_SIZE as usize, 0);
+
+        let ret = unsafe {
+            sys::av_parser_parse2(
+                self.inner.as_ptr(),
+                codec_ctx.as_mut_ptr(),
+                &mut out_data,
+                &mut out_size,
+                if data.is_empty() {
+                    ptr::null()
+                } else {
+                    self.buffer.as_ptr()
+                },
+                size,
+                pts.unwrap_or(NOPTS_VALUE),
+                dts.unwrap_or(NOPTS_VALUE),
+                pos.unwrap_or(-1),
+            )
+        };
+
+        if ret < 0 {
+            return Err(Error::new(ret));
+        }
+
+        if out_data.is_null() || out_size <= 0 {
+            return Ok((ret as usize, None));
+        }
+
+        // the output points into either our input or the parser's internal
+        // buffer, so it has to be copied before the next call
+        let out = unsafe { std::slice::from_raw_parts(out_data, out_size as usize) };
+        let mut packet = Packet::from_slice(out)?;
+
+        let parser = self.as_ref();
+        packet.set_pts(parser.pts);
+        packet.set_dts(parser.dts);
+        packet.set_pos((parser.pos >= 0).then_some(parser.pos));
+        packet.set_key(parser.key_frame == 1);
+
+        Ok((ret as usize, Some(packet)))
+    }
+
+    /// Feeds all of `data` to the parser and returns the packets that were
+    /// completed along the way.
+    pub fn parse_all(
+        &mut self,
+        codec_ctx: &mut CodecContext,
+        mut data: &[u8],
+        mut pts: Option<i64>,
+        mut dts: Option<i64>,
+        pos: Option<i64>,
+    ) -> Result<Vec<Packet>> {
+        let mut packets = Vec::new();
+        let mut offset = 0;
+
+        while !data.is_empty() {
+            // the timestamps belong to the start of the buffer, so only pass
+            // them along with the first chunk
+            let (consumed, packet) = self.parse(
+                codec_ctx,
+                data,
+                pts.take(),
+                dts.take(),
+                pos.map(|pos| pos + offset as i64),
+            )?;
+
+            // a parser that neither consumes input nor completes a packet
+            // would never make progress
+            if consumed == 0 && packet.is_none() {
+                break;
+            }
+
+            packets.extend(packet);
+            data = &data[consumed..];
+            offset += consumed;
+        }
+
+        Ok(packets)
+    }
+
+    /// Returns any packet still buffered in the parser at the end of the
+    /// stream.
+    pub fn flush(&mut self, codec_ctx: &mut CodecContext) -> Result<Option<Packet>> {
+        let (_, packet) = self.parse(codec_ctx, &[], None, None, None)?;
+        Ok(packet)
+    }
+}
+
+impl Drop for Parser {
+    fn drop(&mut self) {
+        unsafe { sys::av_parser_close(self.inner.as_ptr()) }
+    }
+}
@@ -0,0 +1,312 @@
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::str::FromStr;
+
+use libavcodec_sys as sys;
+use num_traits::FromPrimitive;
+
+use crate::AVPixelFormat;
+use crate::error::{Error, Result};
+
+impl AVPixelFormat {
+    /// Describes the layout of the format, or returns `None` for
+    /// [`AVPixelFormat::None`].
+    pub fn descriptor(self) -> Option<PixelFormatDescriptor> {
+        let inner = unsafe { sys::av_pix_fmt_desc_get(self as i32).as_ref()? };
+        Some(PixelFormatDescriptor {
+            format: self,
+            inner,
+        })
+    }
+
+    /// The name libav uses for the format, e.g. `"yuv420p"`.
+    pub fn name(self) -> Option<&'static str> {
+        let name = unsafe { sys::av_get_pix_fmt_name(self as i32) };
+
+        if name.is_null() {
+            None
+        } else {
+            unsafe { CStr::from_ptr(name) }.to_str().ok()
+        }
+    }
+
+    /// Looks up a format by its libav name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = CString::new(name).ok()?;
+        let format = unsafe { sys::av_get_pix_fmt(name.as_ptr()) };
+
+        match AVPixelFormat::from_i32(format)? {
+            AVPixelFormat::None => None,
+            format => Some(format),
+        }
+    }
+
+    /// What is lost when converting an image from this format to `dst`.
+    pub fn loss_to(self, dst: AVPixelFormat, has_alpha: bool) -> PixelFormatLoss {
+        let loss = unsafe { sys::av_get_pix_fmt_loss(dst as i32, self as i32, has_alpha as i32) };
+        PixelFormatLoss::from_raw(loss)
+    }
+
+    /// Chooses whichever of `a` and `b` loses the least when converting an
+    /// image from this format, preferring the smaller format on a tie.
+    pub fn best_of_2(
+        self,
+        a: AVPixelFormat,
+        b: AVPixelFormat,
+        has_alpha: bool,
+    ) -> (AVPixelFormat, PixelFormatLoss) {
+        let mut loss = 0;
+        let best = unsafe {
+            sys::av_find_best_pix_fmt_of_2(
+                a as i32,
+                b as i32,
+                self as i32,
+                has_alpha as i32,
+                &mut loss,
+            )
+        };
+        let best = if best == b as i32 { b } else { a };
+
+        (best, PixelFormatLoss::from_raw(loss))
+    }
+
+    /// Chooses the candidate that loses the least when converting an image
+    /// from this format, e.g. among the formats an encoder supports.
+    pub fn best_of(
+        self,
+        candidates: &[AVPixelFormat],
+        has_alpha: bool,
+    ) -> Option<(AVPixelFormat, PixelFormatLoss)> {
+        let list: Vec<i32> = candidates
+            .iter()
+            .map(|&format| format as i32)
+            .filter(|&format| format != AVPixelFormat::None as i32)
+            .chain([AVPixelFormat::None as i32])
+            .collect();
+
+        let mut loss = 0;
+        let best = unsafe {
+            sys::avcodec_find_best_pix_fmt_of_list(
+                list.as_ptr(),
+                self as i32,
+                has_alpha as i32,
+                &mut loss,
+            )
+        };
+
+        match AVPixelFormat::from_i32(best)? {
+            AVPixelFormat::None => None,
+            best => Some((best, PixelFormatLoss::from_raw(loss))),
+        }
+    }
+}
+
+impl FromStr for AVPixelFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        AVPixelFormat::from_name(s).ok_or(Error::new(-libc::EINVAL))
+    }
+}
+
+impl fmt::Display for AVPixelFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name().unwrap_or("none"))
+    }
+}
+
+/// The information lost by a pixel format conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PixelFormatLoss {
+    /// Chroma is subsampled more.
+    pub resolution: bool,
+    /// Components have fewer bits.
+    pub depth: bool,
+    /// The image is converted to another color space, e.g. YUV to RGB.
+    pub colorspace: bool,
+    /// The alpha channel is dropped.
+    pub alpha: bool,
+    /// Colors are quantized to a palette.
+    pub color_quantization: bool,
+    /// Chroma is dropped, e.g. to gray.
+    pub chroma: bool,
+}
+
+impl PixelFormatLoss {
+    fn from_raw(loss: i32) -> Self {
+        let has = |flag: u32| loss & flag as i32 != 0;
+
+        PixelFormatLoss {
+            resolution: has(sys::FF_LOSS_RESOLUTION),
+            depth: has(sys::FF_LOSS_DEPTH),
+            colorspace: has(sys::FF_LOSS_COLORSPACE),
+            alpha: has(sys::FF_LOSS_ALPHA),
+            color_quantization: has(sys::FF_LOSS_COLORQUANT),
+            chroma: has(sys::FF_LOSS_CHROMA),
+        }
+    }
+
+    pub fn is_lossless(&self) -> bool {
+        *self == PixelFormatLoss::default()
+    }
+}
+
+/// The layout of a pixel format.
+#[derive(Clone, Copy)]
+pub struct PixelFormatDescriptor {
+    format: AVPixelFormat,
+    inner: &'static sys::AVPixFmtDescriptor,
+}
+
+impl PixelFormatDescriptor {
+    pub fn format(&self) -> AVPixelFormat {
+        self.format
+    }
+
+    pub fn name(&self) -> &'static str {
+        unsafe { CStr::from_ptr(self.inner.name) }
+            .to_str()
+            .unwrap_or("")
+    }
+
+    /// The number of components, e.g. 3 for YUV and 4 for RGBA.
+    pub fn component_count(&self) -> usize {
+        self.inner.nb_components as usize
+    }
+
+    pub fn component(&self, index: usize) -> Option<PixelComponent> {
+        if index >= self.component_count() {
+            return None;
+        }
+
+        let comp = &self.inner.comp[index];
+        Some(PixelComponent {
+            plane: comp.plane as usize,
+            step: comp.step as usize,
+            offset: comp.offset as usize,
+            shift: comp.shift as u32,
+            depth: comp.depth as u32,
+        })
+    }
+
+    pub fn components(&self) -> impl Iterator<Item = PixelComponent> + '_ {
+        (0..self.component_count()).filter_map(|i| self.component(i))
+    }
+
+    /// The number of planes the image data is split into.
+    pub fn plane_count(&self) -> usize {
+        unsafe { sys::av_pix_fmt_count_planes(self.format as i32).max(0) as usize }
+    }
+
+    /// Horizontal chroma subsampling as a power of two: the chroma width is
+    /// `-((-width) >> chroma_shift_w)`.
+    pub fn chroma_shift_w(&self) -> u32 {
+        self.inner.log2_chroma_w as u32
+    }
+
+    /// Vertical chroma subsampling as a power of two.
+    pub fn chroma_shift_h(&self) -> u32 {
+        self.inner.log2_chroma_h as u32
+    }
+
+    /// The number of bits per pixel, excluding padding bits.
+    pub fn bits_per_pixel(&self) -> u32 {
+        unsafe { sys::av_get_bits_per_pixel(self.inner) as u32 }
+    }
+
+    /// The number of bits per pixel, including padding bits.
+    pub fn padded_bits_per_pixel(&self) -> u32 {
+        unsafe { sys::av_get_padded_bits_per_pixel(self.inner) as u32 }
+    }
+
+    /// The size in bytes of a row of each plane of an image `width` pixels
+    /// wide, without alignment padding.
+    pub fn line_sizes(&self, width: usize) -> Result<Vec<usize>> {
+        let mut line_sizes = [0; 4];
+        let ret = unsafe {
+            sys::av_image_fill_linesizes(line_sizes.as_mut_ptr(), self.format as i32, width as i32)
+        };
+
+        if ret < 0 {
+            return Err(Error::new(ret));
+        }
+
+        Ok(line_sizes[..self.plane_count()]
+            .iter()
+            .map(|&size| size as usize)
+            .collect())
+    }
+
+    fn has_flag(&self, flag: u32) -> bool {
+        self.inner.flags & flag as u64 != 0
+    }
+
+    pub fn is_big_endian(&self) -> bool {
+        self.has_flag(sys::AV_PIX_FMT_FLAG_BE)
+    }
+
+    /// Whether the pixels are indices into a palette stored in plane 1.
+    pub fn is_palette(&self) -> bool {
+        self.has_flag(sys::AV_PIX_FMT_FLAG_PAL)
+    }
+
+    /// Whether the pixels are packed as bits rather than bytes, e.g. monow.
+    pub fn is_bitstream(&self) -> bool {
+        self.has_flag(sys::AV_PIX_FMT_FLAG_BITSTREAM)
+    }
+
+    /// Whether this is an opaque hardware surface format.
+    pub fn is_hwaccel(&self) -> bool {
+        self.has_flag(sys::AV_PIX_FMT_FLAG_HWACCEL)
+    }
+
+    /// Whether at least one component is stored in its own plane.
+    pub fn is_planar(&self) -> bool {
+        self.has_flag(sys::AV_PIX_FMT_FLAG_PLANAR)
+    }
+
+    pub fn is_rgb(&self) -> bool {
+        self.has_flag(sys::AV_PIX_FMT_FLAG_RGB)
+    }
+
+    pub fn has_alpha(&self) -> bool {
+        self.has_flag(sys::AV_PIX_FMT_FLAG_ALPHA)
+    }
+
+    /// Whether this is raw sensor data with a Bayer color filter pattern.
+    pub fn is_bayer(&self) -> bool {
+        self.has_flag(sys::AV_PIX_FMT_FLAG_BAYER)
+    }
+
+    /// Whether the components are floating point values.
+    pub fn is_float(&self) -> bool {
+        self.has_flag(sys::AV_PIX_FMT_FLAG_FLOAT)
+    }
+}
+
+impl fmt::Debug for PixelFormatDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PixelFormatDescriptor")
+            .field("name", &self.name())
+            .field("components", &self.component_count())
+            .field("planes", &self.plane_count())
+            .finish()
+    }
+}
+
+/// Where and how one component (e.g. luma or red) of a pixel is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PixelComponent {
+    /// The plane the component is stored in.
+    pub plane: usize,
+    /// The distance in bytes between two horizontally adjacent pixels, or in
+    /// bits for bitstream formats.
+    pub step: usize,
+    /// The offset in bytes of the component from the start of the pixel, or
+    /// in bits for bitstream formats.
+    pub offset: usize,
+    /// The number of low bits to shift away to get the value.
+    pub shift: u32,
+    /// The number of bits in the component.
+    pub depth: u32,
+}
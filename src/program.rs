@@ -0,0 +1,139 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ptr::NonNull;
+
+use libavcodec_sys as sys;
+use num_traits::FromPrimitive;
+
+use crate::AVDiscard;
+use crate::stream::metadata_value;
+
+/// A program of a [`FormatContext`](crate::FormatContext), i.e. a group of
+/// streams that belong together, such as a TV channel in an MPEG-TS
+/// multiplex.
+pub struct Program<'a> {
+    inner: NonNull<sys::AVProgram>,
+    _marker: PhantomData<&'a sys::AVProgram>,
+}
+
+/// A program of a [`FormatContext`](crate::FormatContext), mutably borrowed
+/// from it. Dereferences to [`Program`] for the getters.
+pub struct ProgramMut<'a> {
+    program: Program<'a>,
+    _marker: PhantomData<&'a mut sys::AVProgram>,
+}
+
+impl AsRef<sys::AVProgram> for Program<'_> {
+    fn as_ref(&self) -> &sys::AVProgram {
+        unsafe { self.inner.as_ref() }
+    }
+}
+
+impl AsMut<sys::AVProgram> for ProgramMut<'_> {
+    fn as_mut(&mut self) -> &mut sys::AVProgram {
+        unsafe { self.program.inner.as_mut() }
+    }
+}
+
+impl<'a> Deref for ProgramMut<'a> {
+    type Target = Program<'a>;
+
+    fn deref(&self) -> &Program<'a> {
+        &self.program
+    }
+}
+
+impl<'a> Program<'a> {
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid program that is not mutated for `'a`.
+    pub(crate) unsafe fn from_raw(ptr: NonNull<sys::AVProgram>) -> Self {
+        Program {
+            inner: ptr,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *const sys::AVProgram {
+        self.inner.as_ptr()
+    }
+
+    /// The id of the program, which for MPEG-TS is the program number.
+    pub fn id(&self) -> i32 {
+        self.as_ref().id
+    }
+
+    pub fn program_num(&self) -> i32 {
+        self.as_ref().program_num
+    }
+
+    /// The PID of the MPEG-TS program map table, or -1.
+    pub fn pmt_pid(&self) -> i32 {
+        self.as_ref().pmt_pid
+    }
+
+    /// The PID carrying the MPEG-TS program clock reference, or -1.
+    pub fn pcr_pid(&self) -> i32 {
+        self.as_ref().pcr_pid
+    }
+
+    /// The value of a metadata tag such as `"service_name"` or
+    /// `"service_provider"`.
+    pub fn metadata(&self, key: &str) -> Option<&str> {
+        unsafe { metadata_value(self.as_ref().metadata, key) }
+    }
+
+    /// The name of the program, e.g. a TV channel name.
+    pub fn name(&self) -> Option<&str> {
+        self.metadata("service_name")
+    }
+
+    /// The indices of the streams of the program, as used by
+    /// [`FormatContext::stream`](crate::FormatContext::stream).
+    pub fn stream_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        let program = self.as_ref();
+        let indices = if program.stream_index.is_null() {
+            &[][..]
+        } else {
+            unsafe {
+                std::slice::from_raw_parts(program.stream_index, program.nb_stream_indexes as usize)
+            }
+        };
+
+        indices.iter().map(|&index| index as usize)
+    }
+
+    pub fn contains_stream(&self, index: usize) -> bool {
+        self.stream_indices().any(|i| i == index)
+    }
+
+    /// Whether the demuxer skips this program, or `None` if the level is not
+    /// known to this crate.
+    pub fn discard(&self) -> Option<AVDiscard> {
+        AVDiscard::from_i32(self.as_ref().discard)
+    }
+}
+
+impl<'a> ProgramMut<'a> {
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid program that is not otherwise accessed for
+    /// `'a`.
+    pub(crate) unsafe fn from_raw(ptr: NonNull<sys::AVProgram>) -> Self {
+        ProgramMut {
+            program: unsafe { Program::from_raw(ptr) },
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut sys::AVProgram {
+        self.program.inner.as_ptr()
+    }
+
+    /// Makes the demuxer skip this program. For MPEG-TS, this stops parsing
+    /// the PIDs that only belong to discarded programs. The streams of the
+    /// program have their own [discard](crate::StreamMut::set_discard) level.
+    pub fn set_discard(&mut self, discard: AVDiscard) {
+        self.as_mut().discard = discard as _;
+    }
+}
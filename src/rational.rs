@@ -1,5 +1,12 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+use std::time::Duration;
+
 use libavcodec_sys as sys;
 
+use crate::NOPTS_VALUE;
+
 #[derive(Debug, Clone, Copy)]
 pub struct Rational {
     inner: sys::AVRational,
@@ -12,6 +19,12 @@ impl Rational {
         }
     }
 
+    /// Finds the closest rational to `value` whose numerator and denominator
+    /// do not exceed `max` in absolute value.
+    pub fn from_f64(value: f64, max: i32) -> Self {
+        unsafe { sys::av_d2q(value, max) }.into()
+    }
+
     pub fn as_f64(&self) -> f64 {
         // AVRational has q2d method that is supposed to handle this, but
         // bindgen won't import it for some reason
@@ -25,6 +38,57 @@ impl Rational {
     pub fn den(&self) -> i32 {
         self.inner.den
     }
+
+    /// Swaps the numerator and the denominator.
+    pub fn inverse(&self) -> Self {
+        Rational::new(self.inner.den, self.inner.num)
+    }
+
+    /// Reduces the fraction to its lowest terms.
+    pub fn reduce(&self) -> Self {
+        self.reduce_max(i32::MAX)
+    }
+
+    /// Reduces the fraction, approximating it if needed so that the
+    /// numerator and denominator do not exceed `max`.
+    pub fn reduce_max(&self, max: i32) -> Self {
+        let mut num = 0;
+        let mut den = 0;
+
+        unsafe {
+            sys::av_reduce(
+                &mut num,
+                &mut den,
+                self.inner.num as i64,
+                self.inner.den as i64,
+                max as i64,
+            )
+        };
+
+        Rational::new(num, den)
+    }
+
+    // port of av_cmp_q, which is an inline function and therefore missing
+    // from the bindings. returns None if either value is 0/0
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        let a = self.inner;
+        let b = other.inner;
+        let tmp = a.num as i64 * b.den as i64 - b.num as i64 * a.den as i64;
+
+        if tmp != 0 {
+            if (tmp ^ a.den as i64 ^ b.den as i64) < 0 {
+                Some(Ordering::Less)
+            } else {
+                Some(Ordering::Greater)
+            }
+        } else if a.den != 0 && b.den != 0 {
+            Some(Ordering::Equal)
+        } else if a.num != 0 && b.num != 0 {
+            Some((a.num >> 31).cmp(&(b.num >> 31)))
+        } else {
+            None
+        }
+    }
 }
 
 impl From<sys::AVRational> for Rational {
@@ -44,3 +108,275 @@ impl Into<f64> for Rational {
         self.as_f64()
     }
 }
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.inner.num, self.inner.den)
+    }
+}
+
+impl PartialEq for Rational {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Rational {}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    /// Compares the values of the fractions, so 1/2 == 2/4 == -1/-2. 0/0 has
+    /// no value and sorts before everything else so that sorting stays total.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other).unwrap_or_else(|| {
+            // compare only fails if either side is 0/0
+            let is_nan = |r: &Self| r.inner.num == 0 && r.inner.den == 0;
+            is_nan(other).cmp(&is_nan(self))
+        })
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        unsafe { sys::av_add_q(self.inner, rhs.inner) }.into()
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        unsafe { sys::av_sub_q(self.inner, rhs.inner) }.into()
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        unsafe { sys::av_mul_q(self.inner, rhs.inner) }.into()
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        unsafe { sys::av_div_q(self.inner, rhs.inner) }.into()
+    }
+}
+
+/// Rounding mode used when rescaling timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// Round toward zero.
+    Zero,
+    /// Round away from zero.
+    Inf,
+    /// Round toward -infinity.
+    Down,
+    /// Round toward +infinity.
+    Up,
+    /// Round to nearest and halfway cases away from zero.
+    #[default]
+    NearInf,
+}
+
+impl Rounding {
+    fn as_raw(self) -> sys::AVRounding {
+        match self {
+            Rounding::Zero => sys::AVRounding_AV_ROUND_ZERO,
+            Rounding::Inf => sys::AVRounding_AV_ROUND_INF,
+            Rounding::Down => sys::AVRounding_AV_ROUND_DOWN,
+            Rounding::Up => sys::AVRounding_AV_ROUND_UP,
+            Rounding::NearInf => sys::AVRounding_AV_ROUND_NEAR_INF,
+        }
+    }
+}
+
+/// Converts `value` from time base `from` to time base `to`, rounding to the
+/// nearest value.
+pub fn rescale(value: i64, from: Rational, to: Rational) -> i64 {
+    unsafe { sys::av_rescale_q(value, from.inner, to.inner) }
+}
+
+/// Converts `value` from time base `from` to time base `to` with the given
+/// rounding mode. [`NOPTS_VALUE`] and `i64::MAX` are passed through
+/// unchanged.
+pub fn rescale_rnd(value: i64, from: Rational, to: Rational, rounding: Rounding) -> i64 {
+    unsafe {
+        sys::av_rescale_q_rnd(
+            value,
+            from.inner,
+            to.inner,
+            rounding.as_raw() | sys::AVRounding_AV_ROUND_PASS_MINMAX,
+        )
+    }
+}
+
+/// A timestamp along with the time base it is expressed in.
+#[derive(Debug, Clone, Copy)]
+pub struct Timestamp {
+    pub value: i64,
+    pub time_base: Rational,
+}
+
+impl Timestamp {
+    pub fn new(value: i64, time_base: Rational) -> Self {
+        Timestamp { value, time_base }
+    }
+
+    /// Wraps a raw timestamp, returning `None` if it is [`NOPTS_VALUE`].
+    pub fn from_raw(value: i64, time_base: Rational) -> Option<Self> {
+        if value == NOPTS_VALUE {
+            None
+        } else {
+            Some(Timestamp { value, time_base })
+        }
+    }
+
+    /// Converts a duration since the start of the stream into a timestamp in
+    /// the given time base.
+    pub fn from_duration(duration: Duration, time_base: Rational) -> Self {
+        let nanos = i64::try_from(duration.as_nanos()).unwrap_or(i64::MAX);
+
+        Timestamp {
+            value: rescale(nanos, NANOSECONDS, time_base),
+            time_base,
+        }
+    }
+
+    /// Expresses this timestamp in another time base.
+    pub fn rescale(&self, time_base: Rational) -> Self {
+        Timestamp {
+            value: rescale(self.value, self.time_base, time_base),
+            time_base,
+        }
+    }
+
+    pub fn rescale_rnd(&self, time_base: Rational, rounding: Rounding) -> Self {
+        Timestamp {
+            value: rescale_rnd(self.value, self.time_base, time_base, rounding),
+            time_base,
+        }
+    }
+
+    pub fn as_secs_f64(&self) -> f64 {
+        self.value as f64 * self.time_base.as_f64()
+    }
+
+    /// The timestamp as a duration since the start of the stream. Returns
+    /// `None` for negative or unset timestamps, and if the duration does not
+    /// fit in nanoseconds.
+    pub fn to_duration(&self) -> Option<Duration> {
+        if self.value < 0 {
+            return None;
+        }
+
+        // av_rescale_q returns i64::MIN on overflow, and a negative time base
+        // flips the sign
+        let nanos = rescale(self.value, self.time_base, NANOSECONDS);
+        u64::try_from(nanos).ok().map(Duration::from_nanos)
+    }
+}
+
+const NANOSECONDS: Rational = Rational {
+    inner: sys::AVRational {
+        num: 1,
+        den: 1_000_000_000,
+    },
+};
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    /// Compares the points in time, even if the time bases differ.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ret = unsafe {
+            sys::av_compare_ts(
+                self.value,
+                self.time_base.inner,
+                other.value,
+                other.time_base.inner,
+            )
+        };
+
+        ret.cmp(&0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_values() {
+        assert_eq!(Rational::new(1, 2), Rational::new(2, 4));
+        assert_eq!(Rational::new(1, 2), Rational::new(-1, -2));
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+        assert!(Rational::new(-1, 2) < Rational::new(1, -3));
+        assert!(Rational::new(-1, 0) < Rational::new(i32::MIN, 1));
+        assert!(Rational::new(i32::MAX, 1) < Rational::new(1, 0));
+        assert_eq!(Rational::new(1, 0), Rational::new(2, 0));
+    }
+
+    #[test]
+    fn orders_zero_over_zero_first() {
+        let nan = Rational::new(0, 0);
+
+        assert_eq!(nan, nan);
+        assert!(nan < Rational::new(-1, 0));
+        assert!(nan < Rational::new(1, 2));
+        assert!(nan < Rational::new(-1, -2));
+        assert!(nan < Rational::new(0, 1));
+    }
+
+    #[test]
+    fn sorts_consistently() {
+        let mut values = vec![
+            Rational::new(1, 2),
+            Rational::new(0, 0),
+            Rational::new(-1, -2),
+            Rational::new(-1, 0),
+            Rational::new(0, 1),
+            Rational::new(1, 0),
+            Rational::new(-3, 4),
+            Rational::new(0, 0),
+        ];
+        values.sort();
+
+        let raw: Vec<_> = values.iter().map(|r| (r.num(), r.den())).collect();
+        assert_eq!(&raw[..2], &[(0, 0), (0, 0)]);
+        assert_eq!(raw[2], (-1, 0));
+        assert_eq!(raw[3], (-3, 4));
+        assert_eq!(raw[4], (0, 1));
+        assert_eq!(values[5], Rational::new(1, 2));
+        assert_eq!(values[6], Rational::new(1, 2));
+        assert_eq!(raw[7], (1, 0));
+
+        for a in &values {
+            for b in &values {
+                assert_eq!(a.cmp(b), b.cmp(a).reverse());
+            }
+        }
+    }
+}
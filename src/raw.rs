@@ -0,0 +1,497 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::ptr;
+
+use libavcodec_sys as sys;
+use num_traits::FromPrimitive;
+
+use crate::error::{Error, Result};
+use crate::frame::Frame;
+use crate::packet::Packet;
+use crate::{
+    AVError, AVPixelFormat, AVSampleFormat, ChannelLayout, FormatContext, InputFormat, Rational,
+    Sample,
+};
+
+/// Reads frames from a headerless video file, such as a `.yuv` or `.rgb`
+/// file, that holds nothing but the images one after another.
+pub struct RawVideoReader {
+    format_ctx: FormatContext,
+    packet: Packet,
+    width: usize,
+    height: usize,
+    pix_fmt: AVPixelFormat,
+    frame_rate: Rational,
+    frame_size: usize,
+    next_pts: i64,
+}
+
+impl RawVideoReader {
+    pub fn open<P: AsRef<Path>>(
+        path: P,
+        width: usize,
+        height: usize,
+        pix_fmt: AVPixelFormat,
+        frame_rate: Rational,
+    ) -> Result<Self> {
+        let frame_size = image_size(width, height, pix_fmt)?;
+        let format = InputFormat::find("rawvideo").ok_or(Error::Av(AVError::DemuxerNotFound))?;
+        let pix_fmt_name = pix_fmt.name().ok_or(Error::new(-libc::EINVAL))?;
+
+        let format_ctx = FormatContext::open_with_format(
+            path,
+            Some(format),
+            &[
+                ("video_size", &format!("{width}x{height}")),
+                ("pixel_format", pix_fmt_name),
+                ("framerate", &frame_rate.to_string()),
+            ],
+        )?;
+
+        Ok(RawVideoReader {
+            format_ctx,
+            packet: Packet::new()?,
+            width,
+            height,
+            pix_fmt,
+            frame_rate,
+            frame_size,
+            next_pts: 0,
+        })
+    }
+
+    /// The time base of the frame timestamps, `1/frame_rate`.
+    pub fn time_base(&self) -> Rational {
+        Rational::new(self.frame_rate.den(), self.frame_rate.num())
+    }
+
+    /// Reads the next frame, or returns `None` at the end of the file. A
+    /// partial frame at the end of the file is an
+    /// [`InvalidData`](AVError::InvalidData) error.
+    pub fn read_frame(&mut self) -> Result<Option<Frame>> {
+        if !self.format_ctx.read_packet(&mut self.packet)? {
+            return Ok(None);
+        }
+
+        let frame = self.packet_to_frame();
+        self.packet.unref();
+        frame.map(Some)
+    }
+
+    fn packet_to_frame(&mut self) -> Result<Frame> {
+        let data = self.packet.as_slice();
+        if data.len() < self.frame_size {
+            return Err(Error::Av(AVError::InvalidData));
+        }
+
+        let mut frame = video_frame(self.width, self.height, self.pix_fmt)?;
+
+        unsafe {
+            let mut src_data = [ptr::null_mut(); 4];
+            let mut src_linesize = [0; 4];
+
+            let ret = sys::av_image_fill_arrays(
+                src_data.as_mut_ptr(),
+                src_linesize.as_mut_ptr(),
+                data.as_ptr(),
+                self.pix_fmt as i32,
+                self.width as i32,
+                self.height as i32,
+                1,
+            );
+            if ret < 0 {
+                return Err(Error::new(ret));
+            }
+
+            let dst = frame.inner_mut();
+            sys::av_image_copy(
+                dst.data.as_ptr(),
+                dst.linesize.as_ptr(),
+                src_data.map(|ptr| ptr as *const u8).as_ptr(),
+                src_linesize.as_ptr(),
+                self.pix_fmt as i32,
+                self.width as i32,
+                self.height as i32,
+            );
+        }
+
+        frame.set_pts(self.next_pts);
+        self.next_pts += 1;
+        Ok(frame)
+    }
+}
+
+/// Writes frames to a headerless video file, as the images one after another
+/// without any padding.
+pub struct RawVideoWriter<W: Write = BufWriter<File>> {
+    writer: W,
+    width: usize,
+    height: usize,
+    pix_fmt: AVPixelFormat,
+    buffer: Vec<u8>,
+}
+
+impl RawVideoWriter {
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        width: usize,
+        height: usize,
+        pix_fmt: AVPixelFormat,
+    ) -> Result<Self> {
+        let file = File::create(path)?;
+        Self::new(BufWriter::new(file), width, height, pix_fmt)
+    }
+}
+
+impl<W: Write> RawVideoWriter<W> {
+    pub fn new(writer: W, width: usize, height: usize, pix_fmt: AVPixelFormat) -> Result<Self> {
+        let frame_size = image_size(width, height, pix_fmt)?;
+
+        Ok(RawVideoWriter {
+            writer,
+            width,
+            height,
+            pix_fmt,
+            buffer: vec![0; frame_size],
+        })
+    }
+
+    /// Writes a frame, which must have the size and pixel format the writer
+    /// was created with.
+    pub fn write_frame(&mut self, frame: &Frame) -> Result<()> {
+        if frame.width() != self.width as i32
+            || frame.height() != self.height as i32
+            || frame.format() != self.pix_fmt as i32
+        {
+            return Err(Error::new(-libc::EINVAL));
+        }
+
+        let inner = frame.inner();
+        let ret = unsafe {
+            sys::av_image_copy_to_buffer(
+                self.buffer.as_mut_ptr(),
+                self.buffer.len() as i32,
+                inner.data.as_ptr() as *const *const u8,
+                inner.linesize.as_ptr(),
+                self.pix_fmt as i32,
+                self.width as i32,
+                self.height as i32,
+                1,
+            )
+        };
+        if ret < 0 {
+            return Err(Error::new(ret));
+        }
+
+        self.writer.write_all(&self.buffer)?;
+        Ok(())
+    }
+
+    /// Flushes the output and returns the underlying writer.
+    pub fn finish(mut self) -> Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Reads frames from a headerless PCM file, such as a `.pcm` or `.raw` file
+/// of interleaved samples in native byte order.
+pub struct RawAudioReader {
+    format_ctx: FormatContext,
+    packet: Packet,
+    sample_fmt: AVSampleFormat,
+    sample_rate: usize,
+    channel_layout: ChannelLayout,
+    // bytes per sample of all channels
+    sample_size: usize,
+    next_pts: i64,
+}
+
+impl RawAudioReader {
+    /// Opens a file of samples of `sample_fmt`. Since the samples in the file
+    /// are interleaved, the frames use the packed variant of `sample_fmt`.
+    pub fn open<P: AsRef<Path>>(
+        path: P,
+        sample_fmt: AVSampleFormat,
+        sample_rate: usize,
+        channel_layout: ChannelLayout,
+    ) -> Result<Self> {
+        // only formats with a demuxer have a known, non-zero sample size
+        let sample_fmt = sample_fmt.packed();
+        let format_name = pcm_format_name(sample_fmt).ok_or(Error::new(-libc::EINVAL))?;
+        let sample_size = sample_fmt.bytes_per_sample() * channel_layout.count();
+
+        if sample_size == 0 {
+            return Err(Error::new(-libc::EINVAL));
+        }
+
+        let format = InputFormat::find(&format_name).ok_or(Error::Av(AVError::DemuxerNotFound))?;
+
+        let format_ctx = FormatContext::open_with_format(
+            path,
+            Some(format),
+            &[
+                ("sample_rate", &sample_rate.to_string()),
+                ("ch_layout", &channel_layout.describe()?),
+            ],
+        )?;
+
+        Ok(RawAudioReader {
+            format_ctx,
+            packet: Packet::new()?,
+            sample_fmt,
+            sample_rate,
+            channel_layout,
+            sample_size,
+            next_pts: 0,
+        })
+    }
+
+    /// The time base of the frame timestamps, `1/sample_rate`.
+    pub fn time_base(&self) -> Rational {
+        Rational::new(1, self.sample_rate as i32)
+    }
+
+    /// Reads the next frame, or returns `None` at the end of the file. The
+    /// number of samples per frame is chosen by the demuxer. A partial sample
+    /// at the end of the file is an [`InvalidData`](AVError::InvalidData)
+    /// error.
+    pub fn read_frame(&mut self) -> Result<Option<Frame>> {
+        loop {
+            if !self.format_ctx.read_packet(&mut self.packet)? {
+                return Ok(None);
+            }
+
+            let frame = self.packet_to_frame();
+            self.packet.unref();
+
+            // empty packets are skipped
+            if let Some(frame) = frame.transpose() {
+                return frame.map(Some);
+            }
+        }
+    }
+
+    fn packet_to_frame(&mut self) -> Result<Option<Frame>> {
+        let data = self.packet.as_slice();
+        if data.len() % self.sample_size != 0 {
+            return Err(Error::Av(AVError::InvalidData));
+        }
+
+        let sample_count = data.len() / self.sample_size;
+        if sample_count == 0 {
+            return Ok(None);
+        }
+
+        let mut frame = Frame::new()?;
+        frame.allocate_audio_buffer(
+            self.channel_layout.clone(),
+            self.sample_rate,
+            sample_count,
+            self.sample_fmt,
+        )?;
+
+        unsafe { ptr::copy_nonoverlapping(data.as_ptr(), frame.inner_mut().data[0], data.len()) };
+
+        frame.set_pts(self.next_pts);
+        self.next_pts += sample_count as i64;
+        Ok(Some(frame))
+    }
+}
+
+/// Writes frames to a headerless PCM file, as interleaved samples in native
+/// byte order.
+pub struct RawAudioWriter<W: Write = BufWriter<File>> {
+    writer: W,
+    sample_fmt: AVSampleFormat,
+    sample_rate: usize,
+    channel_layout: ChannelLayout,
+    buffer: Vec<u8>,
+}
+
+impl RawAudioWriter {
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        sample_fmt: AVSampleFormat,
+        sample_rate: usize,
+        channel_layout: ChannelLayout,
+    ) -> Result<Self> {
+        let file = File::create(path)?;
+        Ok(Self::new(
+            BufWriter::new(file),
+            sample_fmt,
+            sample_rate,
+            channel_layout,
+        ))
+    }
+}
+
+impl<W: Write> RawAudioWriter<W> {
+    /// Creates a writer of samples of `sample_fmt`. Frames of either its
+    /// packed or its planar variant are accepted, and planar frames are
+    /// interleaved.
+    pub fn new(
+        writer: W,
+        sample_fmt: AVSampleFormat,
+        sample_rate: usize,
+        channel_layout: ChannelLayout,
+    ) -> Self {
+        RawAudioWriter {
+            writer,
+            sample_fmt: sample_fmt.packed(),
+            sample_rate,
+            channel_layout,
+            buffer: Vec::new(),
+        }
+    }
+
+    pub fn write_frame(&mut self, frame: &Frame) -> Result<()> {
+        let format = AVSampleFormat::from_i32(frame.format()).ok_or(Error::new(-libc::EINVAL))?;
+
+        if format.packed() != self.sample_fmt
+            || frame.sample_rate() != self.sample_rate as i32
+            || frame.channel_layout()? != self.channel_layout
+        {
+            return Err(Error::new(-libc::EINVAL));
+        }
+
+        let bytes_per_sample = self.sample_fmt.bytes_per_sample();
+        let channels = self.channel_layout.count();
+        let sample_count = frame.sample_count().max(0) as usize;
+        let size = sample_count * channels * bytes_per_sample;
+
+        let inner = frame.inner();
+        if !format.is_planar() {
+            let data = unsafe { std::slice::from_raw_parts(inner.data[0], size) };
+            self.writer.write_all(data)?;
+            return Ok(());
+        }
+
+        self.buffer.resize(size, 0);
+        for channel in 0..channels {
+            let plane = unsafe {
+                std::slice::from_raw_parts(
+                    *inner.extended_data.add(channel),
+                    sample_count * bytes_per_sample,
+                )
+            };
+
+            for (i, sample) in plane.chunks_exact(bytes_per_sample).enumerate() {
+                let offset = (i * channels + channel) * bytes_per_sample;
+                self.buffer[offset..offset + bytes_per_sample].copy_from_slice(sample);
+            }
+        }
+
+        self.writer.write_all(&self.buffer)?;
+        Ok(())
+    }
+
+    /// Writes interleaved samples directly, e.g. generated test signals.
+    pub fn write_samples<T: Sample>(&mut self, samples: &[T]) -> Result<()> {
+        if T::PACKED != self.sample_fmt {
+            return Err(Error::new(-libc::EINVAL));
+        }
+
+        let data = unsafe {
+            std::slice::from_raw_parts(
+                samples.as_ptr() as *const u8,
+                std::mem::size_of_val(samples),
+            )
+        };
+        self.writer.write_all(data)?;
+        Ok(())
+    }
+
+    /// Flushes the output and returns the underlying writer.
+    pub fn finish(mut self) -> Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+fn image_size(width: usize, height: usize, pix_fmt: AVPixelFormat) -> Result<usize> {
+    let size =
+        unsafe { sys::av_image_get_buffer_size(pix_fmt as i32, width as i32, height as i32, 1) };
+
+    if size < 0 {
+        Err(Error::new(size))
+    } else {
+        Ok(size as usize)
+    }
+}
+
+fn video_frame(width: usize, height: usize, pix_fmt: AVPixelFormat) -> Result<Frame> {
+    let mut frame = Frame::new()?;
+
+    unsafe {
+        frame.inner_mut().width = width as i32;
+        frame.inner_mut().height = height as i32;
+        frame.inner_mut().format = pix_fmt as i32;
+
+        let ret = sys::av_frame_get_buffer(frame.inner_mut(), 0);
+        if ret < 0 {
+            return Err(Error::new(ret));
+        }
+    }
+
+    Ok(frame)
+}
+
+// the name of the demuxer of headerless samples of a packed format
+fn pcm_format_name(sample_fmt: AVSampleFormat) -> Option<String> {
+    let name = match sample_fmt {
+        AVSampleFormat::U8 => return Some("u8".to_string()),
+        AVSampleFormat::S16 => "s16",
+        AVSampleFormat::S32 => "s32",
+        AVSampleFormat::Flt => "f32",
+        AVSampleFormat::Dbl => "f64",
+        _ => return None,
+    };
+
+    let endian = if cfg!(target_endian = "big") {
+        "be"
+    } else {
+        "le"
+    };
+    Some(format!("{name}{endian}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_pcm_demuxers() {
+        let endian = if cfg!(target_endian = "big") {
+            "be"
+        } else {
+            "le"
+        };
+
+        assert_eq!(pcm_format_name(AVSampleFormat::U8).as_deref(), Some("u8"));
+        assert_eq!(
+            pcm_format_name(AVSampleFormat::S16),
+            Some(format!("s16{endian}"))
+        );
+        assert_eq!(
+            pcm_format_name(AVSampleFormat::S32),
+            Some(format!("s32{endian}"))
+        );
+        assert_eq!(
+            pcm_format_name(AVSampleFormat::Flt),
+            Some(format!("f32{endian}"))
+        );
+        assert_eq!(
+            pcm_format_name(AVSampleFormat::Dbl),
+            Some(format!("f64{endian}"))
+        );
+    }
+
+    #[test]
+    fn rejects_formats_without_demuxer() {
+        assert_eq!(pcm_format_name(AVSampleFormat::S16p), None);
+        assert_eq!(pcm_format_name(AVSampleFormat::Fltp), None);
+        assert_eq!(pcm_format_name(AVSampleFormat::None), None);
+    }
+}
@@ -0,0 +1,142 @@
+use libavcodec_sys as sys;
+
+use crate::error::Result;
+use crate::frame::Frame;
+use crate::{AVSampleFormat, ChannelLayout, NOPTS_VALUE, Rational, SwrContext, SwrOptions};
+
+/// A streaming resampler that takes timestamped frames and produces frames
+/// with timestamps in the output sample rate time base (`1/out_sample_rate`).
+///
+/// Timestamp gaps and drift in the input are corrected by libswresample, by
+/// stretching the audio or by inserting silence and dropping samples. Drift
+/// below [`SwrOptions::min_compensation`] is ignored, which defaults to
+/// [`Resampler::DEFAULT_MIN_COMPENSATION`].
+pub struct Resampler {
+    swr: SwrContext,
+    in_sample_rate: i64,
+    in_time_base: Rational,
+    out_sample_rate: i64,
+    out_sample_fmt: AVSampleFormat,
+    out_channel_layout: ChannelLayout,
+    // pts of the next output sample, in 1/out_sample_rate
+    next_pts: Option<i64>,
+}
+
+/// The sample rate, sample format and channel layout of one side of a
+/// [`Resampler`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioSpec {
+    pub sample_rate: usize,
+    pub sample_fmt: AVSampleFormat,
+    pub channel_layout: ChannelLayout,
+}
+
+impl Resampler {
+    /// The drift in seconds that triggers compensation unless
+    /// [`SwrOptions::min_compensation`] is set, the same as the `async` option
+    /// of the `aresample` filter.
+    pub const DEFAULT_MIN_COMPENSATION: f64 = 0.001;
+
+    /// Creates a resampler from `input` frames with timestamps in
+    /// `in_time_base` to `output` frames.
+    pub fn new(
+        input: AudioSpec,
+        in_time_base: Rational,
+        output: AudioSpec,
+        mut options: SwrOptions,
+    ) -> Result<Self> {
+        options
+            .min_compensation
+            .get_or_insert(Self::DEFAULT_MIN_COMPENSATION);
+
+        let swr = SwrContext::get_context_with_options(
+            input.sample_rate,
+            input.sample_fmt,
+            input.channel_layout,
+            output.sample_rate,
+            output.sample_fmt,
+            output.channel_layout.clone(),
+            options,
+        )?;
+
+        Ok(Resampler {
+            swr,
+            in_sample_rate: input.sample_rate as i64,
+            in_time_base,
+            out_sample_rate: output.sample_rate as i64,
+            out_sample_fmt: output.sample_fmt,
+            out_channel_layout: output.channel_layout,
+            next_pts: None,
+        })
+    }
+
+    /// The time base of the output frames.
+    pub fn time_base(&self) -> Rational {
+        Rational::new(1, self.out_sample_rate as i32)
+    }
+
+    /// Resamples `frame`. Returns `None` if the resampler buffered all of the
+    /// input without producing output yet.
+    pub fn convert(&mut self, frame: &Frame) -> Result<Option<Frame>> {
+        let pts = if frame.pts() == NOPTS_VALUE {
+            self.next_pts
+        } else {
+            // swr_next_pts works in units of 1/(in_sample_rate * out_sample_rate)
+            let in_pts = unsafe {
+                sys::av_rescale(
+                    frame.pts(),
+                    self.in_time_base.num() as i64 * self.in_sample_rate * self.out_sample_rate,
+                    self.in_time_base.den() as i64,
+                )
+            };
+            let out_pts = self.swr.next_pts(in_pts);
+
+            Some(rounded_div(out_pts, self.in_sample_rate))
+        };
+
+        let mut output = self.output_frame()?;
+        self.swr.convert_frame(Some(frame), &mut output)?;
+
+        Ok(self.finish(output, pts))
+    }
+
+    /// Drains the samples buffered in the resampler at the end of the stream.
+    pub fn flush(&mut self) -> Result<Option<Frame>> {
+        let mut output = self.output_frame()?;
+        self.swr.convert_frame(None, &mut output)?;
+
+        Ok(self.finish(output, self.next_pts))
+    }
+
+    fn output_frame(&self) -> Result<Frame> {
+        // leaving nb_samples unset lets swr_convert_frame size the buffer
+        let mut output = Frame::new()?;
+        output.set_format(self.out_sample_fmt as i32);
+        output.set_sample_rate(self.out_sample_rate as i32);
+        output.set_channel_layout(self.out_channel_layout.clone())?;
+        Ok(output)
+    }
+
+    fn finish(&mut self, mut output: Frame, pts: Option<i64>) -> Option<Frame> {
+        let samples = output.sample_count() as i64;
+
+        if let Some(pts) = pts {
+            self.next_pts = Some(pts + samples);
+        }
+
+        if samples == 0 {
+            return None;
+        }
+
+        output.set_pts(pts.unwrap_or(NOPTS_VALUE));
+        Some(output)
+    }
+}
+
+fn rounded_div(a: i64, b: i64) -> i64 {
+    if a >= 0 {
+        (a + (b >> 1)) / b
+    } else {
+        (a - (b >> 1)) / b
+    }
+}
@@ -0,0 +1,106 @@
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::str::FromStr;
+
+use libavcodec_sys as sys;
+use num_traits::FromPrimitive;
+
+use crate::AVSampleFormat;
+use crate::error::{Error, Result};
+
+impl AVSampleFormat {
+    /// The name libav uses for the format, e.g. `"fltp"`.
+    pub fn name(self) -> Option<&'static str> {
+        let name = unsafe { sys::av_get_sample_fmt_name(self as i32) };
+
+        if name.is_null() {
+            None
+        } else {
+            unsafe { CStr::from_ptr(name) }.to_str().ok()
+        }
+    }
+
+    /// Looks up a format by its libav name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = CString::new(name).ok()?;
+        let format = unsafe { sys::av_get_sample_fmt(name.as_ptr()) };
+
+        match AVSampleFormat::from_i32(format)? {
+            AVSampleFormat::None => None,
+            format => Some(format),
+        }
+    }
+
+    /// The size of a single sample of a single channel, or 0 for
+    /// [`AVSampleFormat::None`].
+    pub fn bytes_per_sample(self) -> usize {
+        unsafe { sys::av_get_bytes_per_sample(self as i32).max(0) as usize }
+    }
+
+    /// Whether each channel is stored in its own plane.
+    pub fn is_planar(self) -> bool {
+        unsafe { sys::av_sample_fmt_is_planar(self as i32) != 0 }
+    }
+
+    /// The interleaved format with the same sample type, e.g. `Flt` for
+    /// `Fltp`.
+    pub fn packed(self) -> Self {
+        let format = unsafe { sys::av_get_packed_sample_fmt(self as i32) };
+        AVSampleFormat::from_i32(format).unwrap_or(AVSampleFormat::None)
+    }
+
+    /// The planar format with the same sample type, e.g. `Fltp` for `Flt`.
+    pub fn planar(self) -> Self {
+        let format = unsafe { sys::av_get_planar_sample_fmt(self as i32) };
+        AVSampleFormat::from_i32(format).unwrap_or(AVSampleFormat::None)
+    }
+}
+
+impl FromStr for AVSampleFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        AVSampleFormat::from_name(s).ok_or(Error::new(-libc::EINVAL))
+    }
+}
+
+impl fmt::Display for AVSampleFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name().unwrap_or("none"))
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A Rust type that audio samples are stored as.
+pub trait Sample: Copy + private::Sealed {
+    /// The interleaved format of this type.
+    const PACKED: AVSampleFormat;
+    /// The planar format of this type.
+    const PLANAR: AVSampleFormat;
+
+    /// Whether buffers of `format` hold samples of this type.
+    fn is_format(format: AVSampleFormat) -> bool {
+        format == Self::PACKED || format == Self::PLANAR
+    }
+}
+
+macro_rules! impl_sample {
+    ($ty:ty, $packed:ident, $planar:ident) => {
+        impl private::Sealed for $ty {}
+
+        impl Sample for $ty {
+            const PACKED: AVSampleFormat = AVSampleFormat::$packed;
+            const PLANAR: AVSampleFormat = AVSampleFormat::$planar;
+        }
+    };
+}
+
+impl_sample!(u8, U8, U8p);
+impl_sample!(i16, S16, S16p);
+impl_sample!(i32, S32, S32p);
+impl_sample!(i64, S64, S64p);
+impl_sample!(f32, Flt, Fltp);
+impl_sample!(f64, Dbl, Dblp);
@@ -0,0 +1,91 @@
+use num_traits::FromPrimitive;
+
+use crate::error::{Error, Result};
+use crate::frame::Frame;
+use crate::{AVPixelFormat, SwsContext, SwsOptions};
+
+/// A scaler that converts frames of any size and format to a fixed output
+/// size and format.
+///
+/// The underlying [`SwsContext`] is rebuilt whenever the size or format of
+/// the input frames changes, e.g. on a resolution switch in an adaptive
+/// bitrate stream.
+pub struct Scaler {
+    context: Option<SwsContext>,
+    // size and format of the frames the context was built for
+    src: Option<(i32, i32, AVPixelFormat)>,
+    dst_width: usize,
+    dst_height: usize,
+    dst_pix_fmt: AVPixelFormat,
+    options: SwsOptions,
+}
+
+impl Scaler {
+    pub fn new(dst_width: usize, dst_height: usize, dst_pix_fmt: AVPixelFormat) -> Self {
+        Self::with_options(dst_width, dst_height, dst_pix_fmt, SwsOptions::default())
+    }
+
+    pub fn with_options(
+        dst_width: usize,
+        dst_height: usize,
+        dst_pix_fmt: AVPixelFormat,
+        options: SwsOptions,
+    ) -> Self {
+        Scaler {
+            context: None,
+            src: None,
+            dst_width,
+            dst_height,
+            dst_pix_fmt,
+            options,
+        }
+    }
+
+    pub fn dst_width(&self) -> usize {
+        self.dst_width
+    }
+
+    pub fn dst_height(&self) -> usize {
+        self.dst_height
+    }
+
+    pub fn dst_pix_fmt(&self) -> AVPixelFormat {
+        self.dst_pix_fmt
+    }
+
+    /// Changes the output size and format, starting with the next frame.
+    pub fn set_output(&mut self, dst_width: usize, dst_height: usize, dst_pix_fmt: AVPixelFormat) {
+        self.dst_width = dst_width;
+        self.dst_height = dst_height;
+        self.dst_pix_fmt = dst_pix_fmt;
+        self.src = None;
+    }
+
+    /// Scales `src` into a newly allocated frame, which also gets the
+    /// properties of `src` such as its timestamps.
+    pub fn scale(&mut self, src: &Frame) -> Result<Frame> {
+        let mut dst = Frame::new()?;
+        self.context_for(src)?.scale_frame(src, &mut dst)?;
+        dst.copy_props(src)?;
+        Ok(dst)
+    }
+
+    fn context_for(&mut self, frame: &Frame) -> Result<&mut SwsContext> {
+        let pix_fmt = AVPixelFormat::from_i32(frame.format()).ok_or(Error::new(-libc::EINVAL))?;
+        let src = (frame.width(), frame.height(), pix_fmt);
+
+        if self.src != Some(src) || self.context.is_none() {
+            let context = SwsContext::get_cached_context(
+                self.context.take(),
+                (src.0 as usize, src.1 as usize, pix_fmt),
+                (self.dst_width, self.dst_height, self.dst_pix_fmt),
+                self.options,
+            )?;
+
+            self.context = Some(context);
+            self.src = Some(src);
+        }
+
+        Ok(self.context.as_mut().unwrap())
+    }
+}
@@ -0,0 +1,457 @@
+use std::{mem, ptr, slice};
+
+use libavcodec_sys as sys;
+use num_traits::FromPrimitive;
+
+use crate::{AVFrameSideDataType, AVPacketSideDataType, Rational};
+
+/// A side data payload with a known layout that can be read from and written
+/// to frames and packets.
+pub trait SideData: Sized {
+    /// The frame side data type that carries this payload, if any.
+    const FRAME_KIND: Option<AVFrameSideDataType>;
+
+    /// The packet side data type that carries this payload, if any.
+    const PACKET_KIND: Option<AVPacketSideDataType>;
+
+    /// Parses the payload from the raw side data bytes. Returns `None` if the
+    /// data is too short or otherwise malformed.
+    fn from_bytes(data: &[u8]) -> Option<Self>;
+
+    /// Serializes the payload into the raw layout expected by libav.
+    fn to_bytes(&self) -> Vec<u8>;
+}
+
+/// A side data entry borrowed from a [`Frame`](crate::Frame).
+pub struct FrameSideData<'a> {
+    inner: &'a sys::AVFrameSideData,
+}
+
+impl<'a> FrameSideData<'a> {
+    pub(crate) fn new(inner: &'a sys::AVFrameSideData) -> Self {
+        FrameSideData { inner }
+    }
+
+    /// The type of this side data, or `None` if it is not known to this crate.
+    pub fn kind(&self) -> Option<AVFrameSideDataType> {
+        AVFrameSideDataType::from_u32(self.inner.type_ as u32)
+    }
+
+    pub fn data(&self) -> &'a [u8] {
+        if self.inner.data.is_null() || self.inner.size == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.inner.data, self.inner.size as usize) }
+        }
+    }
+
+    /// Parses this side data as `T`. Returns `None` if the type does not match.
+    pub fn parse<T: SideData>(&self) -> Option<T> {
+        if T::FRAME_KIND.is_none() || self.kind() != T::FRAME_KIND {
+            return None;
+        }
+
+        T::from_bytes(self.data())
+    }
+}
+
+/// A side data entry borrowed from a [`Packet`](crate::Packet).
+pub struct PacketSideData<'a> {
+    inner: &'a sys::AVPacketSideData,
+}
+
+impl<'a> PacketSideData<'a> {
+    pub(crate) fn new(inner: &'a sys::AVPacketSideData) -> Self {
+        PacketSideData { inner }
+    }
+
+    /// The type of this side data, or `None` if it is not known to this crate.
+    pub fn kind(&self) -> Option<AVPacketSideDataType> {
+        AVPacketSideDataType::from_u32(self.inner.type_ as u32)
+    }
+
+    pub fn data(&self) -> &'a [u8] {
+        if self.inner.data.is_null() || self.inner.size == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.inner.data, self.inner.size as usize) }
+        }
+    }
+
+    /// Parses this side data as `T`. Returns `None` if the type does not match.
+    pub fn parse<T: SideData>(&self) -> Option<T> {
+        if T::PACKET_KIND.is_none() || self.kind() != T::PACKET_KIND {
+            return None;
+        }
+
+        T::from_bytes(self.data())
+    }
+}
+
+/// Reads a C struct out of a side data buffer, which is not guaranteed to be
+/// aligned for `T`.
+fn read_struct<T: Copy>(data: &[u8]) -> Option<T> {
+    if data.len() < mem::size_of::<T>() {
+        return None;
+    }
+
+    Some(unsafe { ptr::read_unaligned(data.as_ptr() as *const T) })
+}
+
+fn struct_bytes<T: Copy>(value: &T) -> Vec<u8> {
+    let bytes =
+        unsafe { slice::from_raw_parts(value as *const T as *const u8, mem::size_of::<T>()) };
+    bytes.to_vec()
+}
+
+/// A 3x3 affine transformation matrix that must be applied to the decoded
+/// video for correct presentation, stored in 16.16 and 2.30 fixed point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayMatrix(pub [i32; 9]);
+
+impl DisplayMatrix {
+    /// Creates a matrix that rotates the video counter-clockwise by `angle`
+    /// degrees.
+    pub fn from_rotation(angle: f64) -> Self {
+        let mut matrix = [0i32; 9];
+        unsafe { sys::av_display_rotation_set(matrix.as_mut_ptr(), angle) };
+        DisplayMatrix(matrix)
+    }
+
+    /// The counter-clockwise rotation in degrees described by this matrix, in
+    /// the range [-180.0, 180.0]. Returns `None` if the matrix is singular.
+    pub fn rotation(&self) -> Option<f64> {
+        let angle = unsafe { sys::av_display_rotation_get(self.0.as_ptr()) };
+
+        if angle.is_nan() { None } else { Some(angle) }
+    }
+
+    /// Flips the matrix horizontally and/or vertically.
+    pub fn flip(&mut self, horizontal: bool, vertical: bool) {
+        unsafe {
+            sys::av_display_matrix_flip(self.0.as_mut_ptr(), horizontal as i32, vertical as i32)
+        };
+    }
+}
+
+impl SideData for DisplayMatrix {
+    const FRAME_KIND: Option<AVFrameSideDataType> = Some(AVFrameSideDataType::DisplayMatrix);
+    const PACKET_KIND: Option<AVPacketSideDataType> = Some(AVPacketSideDataType::DisplayMatrix);
+
+    fn from_bytes(data: &[u8]) -> Option<Self> {
+        read_struct::<[i32; 9]>(data).map(DisplayMatrix)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        struct_bytes(&self.0)
+    }
+}
+
+/// Mastering display color volume (SMPTE ST 2086).
+#[derive(Debug, Clone, Copy)]
+pub struct MasteringDisplayMetadata {
+    /// CIE 1931 xy chromaticity coordinates of the red, green and blue
+    /// primaries, in that order. Only meaningful if `has_primaries` is set.
+    pub display_primaries: [[Rational; 2]; 3],
+    /// CIE 1931 xy chromaticity coordinates of the white point.
+    pub white_point: [Rational; 2],
+    /// Minimum luminance of the mastering display in cd/m². Only meaningful
+    /// if `has_luminance` is set.
+    pub min_luminance: Rational,
+    /// Maximum luminance of the mastering display in cd/m².
+    pub max_luminance: Rational,
+    pub has_primaries: bool,
+    pub has_luminance: bool,
+}
+
+impl SideData for MasteringDisplayMetadata {
+    const FRAME_KIND: Option<AVFrameSideDataType> =
+        Some(AVFrameSideDataType::MasteringDisplayMetadata);
+    const PACKET_KIND: Option<AVPacketSideDataType> =
+        Some(AVPacketSideDataType::MasteringDisplayMetadata);
+
+    fn from_bytes(data: &[u8]) -> Option<Self> {
+        let raw = read_struct::<sys::AVMasteringDisplayMetadata>(data)?;
+
+        Some(MasteringDisplayMetadata {
+            display_primaries: raw.display_primaries.map(|p| p.map(Rational::from)),
+            white_point: raw.white_point.map(Rational::from),
+            min_luminance: raw.min_luminance.into(),
+            max_luminance: raw.max_luminance.into(),
+            has_primaries: raw.has_primaries != 0,
+            has_luminance: raw.has_luminance != 0,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let raw = sys::AVMasteringDisplayMetadata {
+            display_primaries: self.display_primaries.map(|p| p.map(Into::into)),
+            white_point: self.white_point.map(Into::into),
+            min_luminance: self.min_luminance.into(),
+            max_luminance: self.max_luminance.into(),
+            has_primaries: self.has_primaries as i32,
+            has_luminance: self.has_luminance as i32,
+        };
+
+        struct_bytes(&raw)
+    }
+}
+
+/// Content light level (CTA-861.3).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentLightLevel {
+    /// Maximum content light level in cd/m².
+    pub max_cll: u32,
+    /// Maximum frame-average light level in cd/m².
+    pub max_fall: u32,
+}
+
+impl SideData for ContentLightLevel {
+    const FRAME_KIND: Option<AVFrameSideDataType> = Some(AVFrameSideDataType::ContentLightLevel);
+    const PACKET_KIND: Option<AVPacketSideDataType> = Some(AVPacketSideDataType::ContentLightLevel);
+
+    fn from_bytes(data: &[u8]) -> Option<Self> {
+        let raw = read_struct::<sys::AVContentLightMetadata>(data)?;
+
+        Some(ContentLightLevel {
+            max_cll: raw.MaxCLL,
+            max_fall: raw.MaxFALL,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let raw = sys::AVContentLightMetadata {
+            MaxCLL: self.max_cll,
+            MaxFALL: self.max_fall,
+        };
+
+        struct_bytes(&raw)
+    }
+}
+
+/// ATSC A53 Part 4 closed caption data, stored as a sequence of 3-byte
+/// `cc_data` constructs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct A53Captions(pub Vec<u8>);
+
+/// One `cc_data` construct from an A53 caption payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CcData {
+    pub valid: bool,
+    /// 0 and 1 are CEA-608 field 1 and field 2 byte pairs, 2 and 3 are
+    /// CEA-708 DTVCC packet data and packet start.
+    pub cc_type: u8,
+    pub data: [u8; 2],
+}
+
+impl CcData {
+    /// Whether this construct carries a CEA-608 byte pair.
+    pub fn is_cea608(&self) -> bool {
+        self.cc_type < 2
+    }
+}
+
+impl A53Captions {
+    pub fn cc_data(&self) -> impl Iterator<Item = CcData> + '_ {
+        self.0.chunks_exact(3).map(|chunk| CcData {
+            valid: chunk[0] & 0x04 != 0,
+            cc_type: chunk[0] & 0x03,
+            data: [chunk[1], chunk[2]],
+        })
+    }
+
+    /// The valid CEA-608 byte pairs in this payload, along with the field
+    /// (0 or 1) they belong to.
+    pub fn cea608(&self) -> impl Iterator<Item = (u8, [u8; 2])> + '_ {
+        self.cc_data()
+            .filter(|cc| cc.valid && cc.is_cea608())
+            .map(|cc| (cc.cc_type, cc.data))
+    }
+}
+
+impl SideData for A53Captions {
+    const FRAME_KIND: Option<AVFrameSideDataType> = Some(AVFrameSideDataType::A53Cc);
+    const PACKET_KIND: Option<AVPacketSideDataType> = Some(AVPacketSideDataType::A53Cc);
+
+    fn from_bytes(data: &[u8]) -> Option<Self> {
+        Some(A53Captions(data.to_vec()))
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+}
+
+/// A motion vector exported by the decoder when the `export_mvs` flag is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MotionVector {
+    /// Where the current macroblock comes from; negative value when it comes
+    /// from the past, positive value when it comes from the future.
+    pub source: i32,
+    pub width: u8,
+    pub height: u8,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub dst_x: i16,
+    pub dst_y: i16,
+    pub flags: u64,
+    pub motion_x: i32,
+    pub motion_y: i32,
+    pub motion_scale: u16,
+}
+
+impl From<sys::AVMotionVector> for MotionVector {
+    fn from(mv: sys::AVMotionVector) -> Self {
+        MotionVector {
+            source: mv.source,
+            width: mv.w,
+            height: mv.h,
+            src_x: mv.src_x,
+            src_y: mv.src_y,
+            dst_x: mv.dst_x,
+            dst_y: mv.dst_y,
+            flags: mv.flags,
+            motion_x: mv.motion_x,
+            motion_y: mv.motion_y,
+            motion_scale: mv.motion_scale,
+        }
+    }
+}
+
+impl From<MotionVector> for sys::AVMotionVector {
+    fn from(mv: MotionVector) -> Self {
+        sys::AVMotionVector {
+            source: mv.source,
+            w: mv.width,
+            h: mv.height,
+            src_x: mv.src_x,
+            src_y: mv.src_y,
+            dst_x: mv.dst_x,
+            dst_y: mv.dst_y,
+            flags: mv.flags,
+            motion_x: mv.motion_x,
+            motion_y: mv.motion_y,
+            motion_scale: mv.motion_scale,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MotionVectors(pub Vec<MotionVector>);
+
+impl SideData for MotionVectors {
+    const FRAME_KIND: Option<AVFrameSideDataType> = Some(AVFrameSideDataType::MotionVectors);
+    const PACKET_KIND: Option<AVPacketSideDataType> = None;
+
+    fn from_bytes(data: &[u8]) -> Option<Self> {
+        let vectors = data
+            .chunks_exact(mem::size_of::<sys::AVMotionVector>())
+            .filter_map(read_struct::<sys::AVMotionVector>)
+            .map(MotionVector::from)
+            .collect();
+
+        Some(MotionVectors(vectors))
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0
+            .iter()
+            .flat_map(|&mv| struct_bytes(&sys::AVMotionVector::from(mv)))
+            .collect()
+    }
+}
+
+/// Number of samples the decoder should skip at the start and end of the
+/// packet (typically encoder delay and padding).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkipSamples {
+    pub start: u32,
+    pub end: u32,
+    pub start_reason: u8,
+    /// 0 for padding silence, 1 for convergence.
+    pub end_reason: u8,
+}
+
+impl SideData for SkipSamples {
+    const FRAME_KIND: Option<AVFrameSideDataType> = Some(AVFrameSideDataType::SkipSamples);
+    const PACKET_KIND: Option<AVPacketSideDataType> = Some(AVPacketSideDataType::SkipSamples);
+
+    fn from_bytes(data: &[u8]) -> Option<Self> {
+        if data.len() < 10 {
+            return None;
+        }
+
+        Some(SkipSamples {
+            start: u32::from_le_bytes(data[0..4].try_into().unwrap()),
+            end: u32::from_le_bytes(data[4..8].try_into().unwrap()),
+            start_reason: data[8],
+            end_reason: data[9],
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(10);
+        data.extend_from_slice(&self.start.to_le_bytes());
+        data.extend_from_slice(&self.end.to_le_bytes());
+        data.push(self.start_reason);
+        data.push(self.end_reason);
+        data
+    }
+}
+
+/// ReplayGain information. Gains are in dB and peaks are relative to full
+/// scale; `None` means the value is unknown.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReplayGain {
+    pub track_gain: Option<f32>,
+    pub track_peak: Option<f32>,
+    pub album_gain: Option<f32>,
+    pub album_peak: Option<f32>,
+}
+
+impl ReplayGain {
+    // gains are stored in microbels with INT32_MIN meaning unknown, peaks in
+    // units of 1/100000 with 0 meaning unknown
+    fn gain_from_raw(gain: i32) -> Option<f32> {
+        (gain != i32::MIN).then(|| gain as f32 / 100000.0)
+    }
+
+    fn peak_from_raw(peak: u32) -> Option<f32> {
+        (peak != 0).then(|| peak as f32 / 100000.0)
+    }
+
+    fn gain_to_raw(gain: Option<f32>) -> i32 {
+        gain.map_or(i32::MIN, |g| (g * 100000.0).round() as i32)
+    }
+
+    fn peak_to_raw(peak: Option<f32>) -> u32 {
+        peak.map_or(0, |p| (p * 100000.0).round() as u32)
+    }
+}
+
+impl SideData for ReplayGain {
+    const FRAME_KIND: Option<AVFrameSideDataType> = Some(AVFrameSideDataType::ReplayGain);
+    const PACKET_KIND: Option<AVPacketSideDataType> = Some(AVPacketSideDataType::ReplayGain);
+
+    fn from_bytes(data: &[u8]) -> Option<Self> {
+        let raw = read_struct::<sys::AVReplayGain>(data)?;
+
+        Some(ReplayGain {
+            track_gain: Self::gain_from_raw(raw.track_gain),
+            track_peak: Self::peak_from_raw(raw.track_peak),
+            album_gain: Self::gain_from_raw(raw.album_gain),
+            album_peak: Self::peak_from_raw(raw.album_peak),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let raw = sys::AVReplayGain {
+            track_gain: Self::gain_to_raw(self.track_gain),
+            track_peak: Self::peak_to_raw(self.track_peak),
+            album_gain: Self::gain_to_raw(self.album_gain),
+            album_peak: Self::peak_to_raw(self.album_peak),
+        };
+
+        struct_bytes(&raw)
+    }
+}
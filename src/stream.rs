@@ -1,32 +1,59 @@
-use std::ptr::NonNull;
+use std::ffi::{CStr, CString};
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ptr::{self, NonNull};
 use std::time::Duration;
 
 use libavcodec_sys as sys;
 use num_traits::FromPrimitive;
 
-use crate::{AVCodecId, AVMediaType, AVSampleFormat, CodecContext, Error, Rational};
+use crate::{
+    AVCodecId, AVDiscard, AVMediaType, AVSampleFormat, ChannelLayout, CodecContext,
+    CodecParameters, Error, Packet, Rational, Timestamp,
+};
 
-pub struct Stream {
-    pub(crate) inner: NonNull<sys::AVStream>,
+/// A stream of a [`FormatContext`](crate::FormatContext), borrowed from it.
+pub struct Stream<'a> {
+    inner: NonNull<sys::AVStream>,
+    _marker: PhantomData<&'a sys::AVStream>,
 }
 
-unsafe impl Send for Stream {}
+/// A stream of a [`FormatContext`](crate::FormatContext), mutably borrowed
+/// from it. Dereferences to [`Stream`] for the getters.
+pub struct StreamMut<'a> {
+    stream: Stream<'a>,
+    _marker: PhantomData<&'a mut sys::AVStream>,
+}
 
-impl AsRef<sys::AVStream> for Stream {
+impl AsRef<sys::AVStream> for Stream<'_> {
     fn as_ref(&self) -> &sys::AVStream {
         unsafe { self.inner.as_ref() }
     }
 }
 
-impl AsMut<sys::AVStream> for Stream {
+impl AsMut<sys::AVStream> for StreamMut<'_> {
     fn as_mut(&mut self) -> &mut sys::AVStream {
-        unsafe { self.inner.as_mut() }
+        unsafe { self.stream.inner.as_mut() }
     }
 }
 
-impl Stream {
-    pub fn as_mut_ptr(&mut self) -> *mut sys::AVStream {
-        unsafe { self.inner.as_mut() }
+impl<'a> Deref for StreamMut<'a> {
+    type Target = Stream<'a>;
+
+    fn deref(&self) -> &Stream<'a> {
+        &self.stream
+    }
+}
+
+impl<'a> Stream<'a> {
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid stream that is not mutated for `'a`.
+    pub(crate) unsafe fn from_raw(ptr: NonNull<sys::AVStream>) -> Self {
+        Stream {
+            inner: ptr,
+            _marker: PhantomData,
+        }
     }
 
     pub fn as_ptr(&self) -> *const sys::AVStream {
@@ -37,8 +64,8 @@ impl Stream {
         self.as_ref().index
     }
 
-    pub fn codecpar(&mut self) -> &mut sys::AVCodecParameters {
-        unsafe { &mut *self.as_mut().codecpar }
+    pub fn codecpar(&self) -> &CodecParameters {
+        unsafe { CodecParameters::from_raw(self.as_ref().codecpar) }
     }
 
     pub fn time_base(&self) -> Rational {
@@ -54,30 +81,131 @@ impl Stream {
     }
 
     pub fn duration(&self) -> Option<Duration> {
-        let time_base = self.time_base();
-        let duration_ts = self.duration_ts();
+        self.timestamp(self.duration_ts())?.to_duration()
+    }
+
+    /// The presentation time of the first frame of the stream, if known.
+    pub fn start_timestamp(&self) -> Option<Timestamp> {
+        self.timestamp(self.start_time())
+    }
+
+    /// Wraps a raw timestamp in this stream's time base, returning `None` if
+    /// it is unset.
+    pub fn timestamp(&self, value: i64) -> Option<Timestamp> {
+        Timestamp::from_raw(value, self.time_base())
+    }
+
+    /// The media type of the stream, or `None` if it is not known to this
+    /// crate.
+    pub fn codec_type(&self) -> Option<AVMediaType> {
+        self.codecpar().codec_type()
+    }
+
+    /// The codec of the stream, or `None` if it is not known to this crate.
+    pub fn codec_id(&self) -> Option<AVCodecId> {
+        self.codecpar().codec_id()
+    }
+
+    pub fn apply_parameters_to_context(&self, codec_ctx: &mut CodecContext) -> crate::Result<()> {
+        self.codecpar().to_context(codec_ctx)
+    }
 
-        if duration_ts < 0 {
-            return None;
+    /// The cover image of an [attached picture](Disposition::attached_pic)
+    /// stream, e.g. album art, as a single encoded packet.
+    pub fn attached_pic(&self) -> crate::Result<Option<Packet>> {
+        let pic = &self.as_ref().attached_pic;
+
+        if !self.disposition().attached_pic || pic.data.is_null() || pic.size <= 0 {
+            return Ok(None);
         }
 
-        Some(Duration::from_secs_f64(duration_ts as f64 * time_base.as_f64()))
+        Packet::from_raw_ref(pic).map(Some)
     }
 
-    pub fn codec_type(&self) -> AVMediaType {
-        AVMediaType::from_i32(unsafe { (*self.as_ref().codecpar).codec_type }).unwrap()
+    pub fn disposition(&self) -> Disposition {
+        Disposition::from_raw(self.as_ref().disposition)
     }
 
-    pub fn codec_id(&self) -> AVCodecId {
-        AVCodecId::from_i32(unsafe { (*self.as_ref().codecpar).codec_id as i32 }).unwrap()
+    /// The ISO 639-2 language code of the stream, e.g. `"eng"`, if tagged.
+    pub fn language(&self) -> Option<&str> {
+        self.metadata("language")
     }
 
-    pub fn apply_parameters_to_context(
-        &mut self,
-        codec_ctx: &mut CodecContext,
-    ) -> crate::Result<()> {
-        let ret =
-            unsafe { sys::avcodec_parameters_to_context(codec_ctx.as_mut_ptr(), self.codecpar()) };
+    /// The value of a metadata tag such as `"title"` or `"handler_name"`.
+    pub fn metadata(&self, key: &str) -> Option<&str> {
+        unsafe { metadata_value(self.as_ref().metadata, key) }
+    }
+
+    /// Whether the demuxer drops the packets of this stream, or `None` if the
+    /// level is not known to this crate.
+    pub fn discard(&self) -> Option<AVDiscard> {
+        AVDiscard::from_i32(self.as_ref().discard)
+    }
+
+    /// The average frame rate of video, or 0/1 if unknown.
+    pub fn avg_frame_rate(&self) -> Rational {
+        self.as_ref().avg_frame_rate.into()
+    }
+
+    /// The lowest frame rate that all timestamps of the video can be
+    /// represented in, as guessed by the demuxer, or 0/1 if unknown.
+    pub fn real_frame_rate(&self) -> Rational {
+        self.as_ref().r_frame_rate.into()
+    }
+
+    /// The shape of a pixel as set by the container, or 0/1 if unknown. This
+    /// takes precedence over the one in [`codecpar`](Self::codecpar).
+    pub fn sample_aspect_ratio(&self) -> Rational {
+        self.as_ref().sample_aspect_ratio.into()
+    }
+
+    /// The number of frames in the stream, if the container records it.
+    pub fn frame_count(&self) -> Option<u64> {
+        match self.as_ref().nb_frames {
+            n if n > 0 => Some(n as u64),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> StreamMut<'a> {
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid stream that is not otherwise accessed for
+    /// `'a`.
+    pub(crate) unsafe fn from_raw(ptr: NonNull<sys::AVStream>) -> Self {
+        StreamMut {
+            stream: unsafe { Stream::from_raw(ptr) },
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut sys::AVStream {
+        self.stream.inner.as_ptr()
+    }
+
+    pub fn codecpar_mut(&mut self) -> &mut CodecParameters {
+        unsafe { CodecParameters::from_raw_mut(self.as_mut().codecpar) }
+    }
+
+    pub fn set_time_base(&mut self, time_base: Rational) {
+        self.as_mut().time_base = time_base.into();
+    }
+
+    pub fn set_disposition(&mut self, disposition: Disposition) {
+        self.as_mut().disposition = disposition.as_raw();
+    }
+
+    pub fn set_language(&mut self, language: &str) -> crate::Result<()> {
+        self.set_metadata("language", language)
+    }
+
+    pub fn set_metadata(&mut self, key: &str, value: &str) -> crate::Result<()> {
+        let key = CString::new(key).map_err(|_| Error::NulByte)?;
+        let value = CString::new(value).map_err(|_| Error::NulByte)?;
+        let ret = unsafe {
+            sys::av_dict_set(&mut self.as_mut().metadata, key.as_ptr(), value.as_ptr(), 0)
+        };
 
         if ret < 0 {
             Err(Error::new(ret))
@@ -86,6 +214,22 @@ impl Stream {
         }
     }
 
+    /// Makes the demuxer drop some or all of the packets of this stream, e.g.
+    /// [`AVDiscard::All`] for streams that are not needed.
+    pub fn set_discard(&mut self, discard: AVDiscard) {
+        self.as_mut().discard = discard as _;
+    }
+
+    pub fn set_avg_frame_rate(&mut self, frame_rate: Rational) {
+        self.as_mut().avg_frame_rate = frame_rate.into();
+    }
+
+    pub fn set_sample_aspect_ratio(&mut self, ratio: Rational) {
+        self.as_mut().sample_aspect_ratio = ratio.into();
+    }
+
+    /// Sets the codec parameters of an audio stream, and its time base to
+    /// `1/sample_rate`. The bit rate is left for the muxer to derive.
     pub fn set_audio_codec_params(
         &mut self,
         codec_type: AVMediaType,
@@ -94,22 +238,126 @@ impl Stream {
         channels: usize,
         sample_fmt: AVSampleFormat,
     ) -> crate::Result<()> {
-        unsafe {
-            let codecpar = self.codecpar();
-            codecpar.codec_type = codec_type as i32;
-            codecpar.codec_id = codec_id as _;
-            codecpar.sample_rate = sample_rate as i32;
-            codecpar.format = sample_fmt as i32;
-            codecpar.bit_rate = (sample_rate * 16) as i64; // 16 bits per sample
-            sys::av_channel_layout_default(&mut codecpar.ch_layout, channels as i32);
-
-            // Set stream time base
-            self.as_mut().time_base = sys::AVRational {
-                num: 1,
-                den: sample_rate as i32,
-            };
-        }
+        let codecpar = self.codecpar_mut();
+        codecpar.set_codec_type(codec_type);
+        codecpar.set_codec_id(codec_id);
+        codecpar.set_sample_rate(sample_rate);
+        codecpar.set_sample_format(sample_fmt);
+        codecpar.set_channel_layout(ChannelLayout::new(channels))?;
+
+        // Set stream time base
+        self.set_time_base(Rational::new(1, sample_rate as i32));
 
         Ok(())
     }
 }
+
+/// Looks up a metadata tag in a dictionary owned by libav.
+///
+/// # Safety
+///
+/// `dict` must be null or valid for `'a`.
+pub(crate) unsafe fn metadata_value<'a>(
+    dict: *const sys::AVDictionary,
+    key: &str,
+) -> Option<&'a str> {
+    let key = CString::new(key).ok()?;
+    let entry = unsafe { sys::av_dict_get(dict, key.as_ptr(), ptr::null(), 0) };
+
+    unsafe { CStr::from_ptr(entry.as_ref()?.value) }
+        .to_str()
+        .ok()
+}
+
+/// How a stream is meant to be used, e.g. whether players should pick it by
+/// default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Disposition {
+    /// Players should select this stream if the user did not choose one.
+    pub default: bool,
+    /// A dubbed audio track.
+    pub dub: bool,
+    /// The audio in the original language.
+    pub original: bool,
+    /// Commentary.
+    pub comment: bool,
+    pub lyrics: bool,
+    pub karaoke: bool,
+    /// Subtitles that should be shown even if subtitles are off, e.g. for
+    /// foreign dialogue.
+    pub forced: bool,
+    pub hearing_impaired: bool,
+    pub visual_impaired: bool,
+    /// Audio with only music and effects, without dialogue.
+    pub clean_effects: bool,
+    /// A single cover image, returned as a single packet.
+    pub attached_pic: bool,
+    /// Thumbnail images associated with the video.
+    pub timed_thumbnails: bool,
+    /// Content that is not part of the scene, e.g. narration.
+    pub non_diegetic: bool,
+    /// Subtitles transcribing the dialogue.
+    pub captions: bool,
+    /// Subtitles or audio describing the video.
+    pub descriptions: bool,
+    /// Data that is not meant to be presented.
+    pub metadata: bool,
+    /// A stream that is only meaningful combined with another, e.g. the
+    /// second view of stereoscopic video.
+    pub dependent: bool,
+    /// A video stream holding a single still image.
+    pub still_image: bool,
+}
+
+impl Disposition {
+    fn from_raw(disposition: i32) -> Self {
+        let has = |flag: u32| disposition & flag as i32 != 0;
+
+        Disposition {
+            default: has(sys::AV_DISPOSITION_DEFAULT),
+            dub: has(sys::AV_DISPOSITION_DUB),
+            original: has(sys::AV_DISPOSITION_ORIGINAL),
+            comment: has(sys::AV_DISPOSITION_COMMENT),
+            lyrics: has(sys::AV_DISPOSITION_LYRICS),
+            karaoke: has(sys::AV_DISPOSITION_KARAOKE),
+            forced: has(sys::AV_DISPOSITION_FORCED),
+            hearing_impaired: has(sys::AV_DISPOSITION_HEARING_IMPAIRED),
+            visual_impaired: has(sys::AV_DISPOSITION_VISUAL_IMPAIRED),
+            clean_effects: has(sys::AV_DISPOSITION_CLEAN_EFFECTS),
+            attached_pic: has(sys::AV_DISPOSITION_ATTACHED_PIC),
+            timed_thumbnails: has(sys::AV_DISPOSITION_TIMED_THUMBNAILS),
+            non_diegetic: has(sys::AV_DISPOSITION_NON_DIEGETIC),
+            captions: has(sys::AV_DISPOSITION_CAPTIONS),
+            descriptions: has(sys::AV_DISPOSITION_DESCRIPTIONS),
+            metadata: has(sys::AV_DISPOSITION_METADATA),
+            dependent: has(sys::AV_DISPOSITION_DEPENDENT),
+            still_image: has(sys::AV_DISPOSITION_STILL_IMAGE),
+        }
+    }
+
+    fn as_raw(self) -> i32 {
+        [
+            (self.default, sys::AV_DISPOSITION_DEFAULT),
+            (self.dub, sys::AV_DISPOSITION_DUB),
+            (self.original, sys::AV_DISPOSITION_ORIGINAL),
+            (self.comment, sys::AV_DISPOSITION_COMMENT),
+            (self.lyrics, sys::AV_DISPOSITION_LYRICS),
+            (self.karaoke, sys::AV_DISPOSITION_KARAOKE),
+            (self.forced, sys::AV_DISPOSITION_FORCED),
+            (self.hearing_impaired, sys::AV_DISPOSITION_HEARING_IMPAIRED),
+            (self.visual_impaired, sys::AV_DISPOSITION_VISUAL_IMPAIRED),
+            (self.clean_effects, sys::AV_DISPOSITION_CLEAN_EFFECTS),
+            (self.attached_pic, sys::AV_DISPOSITION_ATTACHED_PIC),
+            (self.timed_thumbnails, sys::AV_DISPOSITION_TIMED_THUMBNAILS),
+            (self.non_diegetic, sys::AV_DISPOSITION_NON_DIEGETIC),
+            (self.captions, sys::AV_DISPOSITION_CAPTIONS),
+            (self.descriptions, sys::AV_DISPOSITION_DESCRIPTIONS),
+            (self.metadata, sys::AV_DISPOSITION_METADATA),
+            (self.dependent, sys::AV_DISPOSITION_DEPENDENT),
+            (self.still_image, sys::AV_DISPOSITION_STILL_IMAGE),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .fold(0, |raw, (_, flag)| raw | *flag as i32)
+    }
+}
@@ -0,0 +1,278 @@
+use std::ffi::{CStr, CString};
+use std::mem;
+use std::slice;
+use std::time::Duration;
+
+use libavcodec_sys as sys;
+use num_traits::FromPrimitive;
+
+use crate::error::{Error, Result};
+use crate::{AVSubtitleType, NOPTS_VALUE};
+
+/// A decoded subtitle event, or one to be encoded.
+///
+/// The rects are allocated with libav's allocator so that decoded and
+/// user-built subtitles are both released by `avsubtitle_free`.
+pub struct Subtitle {
+    inner: sys::AVSubtitle,
+}
+
+unsafe impl Send for Subtitle {}
+
+impl AsRef<sys::AVSubtitle> for Subtitle {
+    fn as_ref(&self) -> &sys::AVSubtitle {
+        &self.inner
+    }
+}
+
+impl AsMut<sys::AVSubtitle> for Subtitle {
+    fn as_mut(&mut self) -> &mut sys::AVSubtitle {
+        &mut self.inner
+    }
+}
+
+impl Subtitle {
+    /// Creates an empty text subtitle with no timestamp.
+    pub fn new() -> Self {
+        let mut inner: sys::AVSubtitle = unsafe { mem::zeroed() };
+        inner.format = 1;
+        inner.pts = NOPTS_VALUE;
+
+        Subtitle { inner }
+    }
+
+    pub(crate) fn zeroed() -> Self {
+        Subtitle {
+            inner: unsafe { mem::zeroed() },
+        }
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut sys::AVSubtitle {
+        &mut self.inner
+    }
+
+    pub fn as_ptr(&self) -> *const sys::AVSubtitle {
+        &self.inner
+    }
+
+    /// Presentation timestamp in `AV_TIME_BASE` (microsecond) units, if known.
+    pub fn pts(&self) -> Option<i64> {
+        if self.inner.pts == NOPTS_VALUE {
+            None
+        } else {
+            Some(self.inner.pts)
+        }
+    }
+
+    pub fn set_pts(&mut self, pts: Option<i64>) {
+        self.inner.pts = pts.unwrap_or(NOPTS_VALUE);
+    }
+
+    /// When to start displaying the subtitle, relative to `pts`.
+    pub fn start_display_time(&self) -> Duration {
+        Duration::from_millis(self.inner.start_display_time as u64)
+    }
+
+    pub fn set_start_display_time(&mut self, time: Duration) {
+        self.inner.start_display_time = time.as_millis() as u32;
+    }
+
+    /// When to stop displaying the subtitle, relative to `pts`.
+    pub fn end_display_time(&self) -> Duration {
+        Duration::from_millis(self.inner.end_display_time as u64)
+    }
+
+    pub fn set_end_display_time(&mut self, time: Duration) {
+        self.inner.end_display_time = time.as_millis() as u32;
+    }
+
+    /// Whether this subtitle is made of bitmaps rather than text.
+    pub fn is_bitmap(&self) -> bool {
+        self.inner.format == 0
+    }
+
+    pub fn rects(&self) -> impl Iterator<Item = SubtitleRect<'_>> {
+        let rects = if self.inner.rects.is_null() {
+            &[][..]
+        } else {
+            unsafe { slice::from_raw_parts(self.inner.rects, self.inner.num_rects as usize) }
+        };
+
+        rects
+            .iter()
+            .filter_map(|&ptr| unsafe { ptr.as_ref() })
+            .map(|inner| SubtitleRect { inner })
+    }
+
+    /// Appends a plain text rect. libavcodec cannot encode these, so use
+    /// [`Self::add_ass`] for subtitles that are encoded.
+    pub fn add_text(&mut self, text: &str) -> Result<()> {
+        let text = CString::new(text).map_err(|_| Error::NulByte)?;
+        let rect = self.push_rect(AVSubtitleType::Text)?;
+
+        rect.text = unsafe { sys::av_strdup(text.as_ptr()) };
+        if rect.text.is_null() {
+            return Err(Error::Alloc);
+        }
+
+        Ok(())
+    }
+
+    /// Appends an ASS rect. `dialogue` is an ASS `Dialogue` event without
+    /// the `Dialogue:` prefix, as produced by libavcodec's text decoders:
+    /// `ReadOrder,Layer,Style,Name,MarginL,MarginR,MarginV,Effect,Text`.
+    pub fn add_ass(&mut self, dialogue: &str) -> Result<()> {
+        let dialogue = CString::new(dialogue).map_err(|_| Error::NulByte)?;
+        let rect = self.push_rect(AVSubtitleType::Ass)?;
+
+        rect.ass = unsafe { sys::av_strdup(dialogue.as_ptr()) };
+        if rect.ass.is_null() {
+            return Err(Error::Alloc);
+        }
+
+        Ok(())
+    }
+
+    fn push_rect(&mut self, kind: AVSubtitleType) -> Result<&mut sys::AVSubtitleRect> {
+        unsafe {
+            let rect = sys::av_mallocz(mem::size_of::<sys::AVSubtitleRect>() as _)
+                as *mut sys::AVSubtitleRect;
+            if rect.is_null() {
+                return Err(Error::Alloc);
+            }
+
+            let count = self.inner.num_rects as usize;
+            let rects = sys::av_realloc_array(
+                self.inner.rects as *mut _,
+                (count + 1) as _,
+                mem::size_of::<*mut sys::AVSubtitleRect>() as _,
+            ) as *mut *mut sys::AVSubtitleRect;
+
+            if rects.is_null() {
+                sys::av_free(rect as *mut _);
+                return Err(Error::Alloc);
+            }
+
+            *rects.add(count) = rect;
+            self.inner.rects = rects;
+            self.inner.num_rects += 1;
+
+            (*rect).type_ = kind as _;
+            Ok(&mut *rect)
+        }
+    }
+}
+
+impl Default for Subtitle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Subtitle {
+    fn drop(&mut self) {
+        unsafe { sys::avsubtitle_free(&mut self.inner) }
+    }
+}
+
+/// A single region of a subtitle event.
+pub struct SubtitleRect<'a> {
+    inner: &'a sys::AVSubtitleRect,
+}
+
+impl<'a> SubtitleRect<'a> {
+    pub fn kind(&self) -> AVSubtitleType {
+        AVSubtitleType::from_u32(self.inner.type_ as u32).unwrap_or(AVSubtitleType::None)
+    }
+
+    /// The plain text of a text rect.
+    pub fn text(&self) -> Option<&'a str> {
+        unsafe { cstr_opt(self.inner.text) }
+    }
+
+    /// The ASS dialogue event of an ASS rect.
+    pub fn ass(&self) -> Option<&'a str> {
+        unsafe { cstr_opt(self.inner.ass) }
+    }
+
+    /// The text of this rect without any ASS event fields: the plain text
+    /// for text rects, or the `Text` field of the dialogue for ASS rects.
+    /// Inline override tags are left intact.
+    pub fn dialogue_text(&self) -> Option<&'a str> {
+        match self.kind() {
+            AVSubtitleType::Text => self.text(),
+            AVSubtitleType::Ass => self
+                .ass()
+                .map(|ass| ass.splitn(9, ',').last().unwrap_or("")),
+            _ => None,
+        }
+    }
+
+    /// The image of a bitmap rect.
+    pub fn bitmap(&self) -> Option<SubtitleBitmap<'a>> {
+        if self.kind() != AVSubtitleType::Bitmap || self.inner.data[0].is_null() {
+            return None;
+        }
+
+        Some(SubtitleBitmap { inner: self.inner })
+    }
+}
+
+/// A paletted image from a bitmap subtitle (e.g. DVD or PGS subtitles).
+pub struct SubtitleBitmap<'a> {
+    inner: &'a sys::AVSubtitleRect,
+}
+
+impl<'a> SubtitleBitmap<'a> {
+    pub fn x(&self) -> i32 {
+        self.inner.x
+    }
+
+    pub fn y(&self) -> i32 {
+        self.inner.y
+    }
+
+    pub fn width(&self) -> i32 {
+        self.inner.w
+    }
+
+    pub fn height(&self) -> i32 {
+        self.inner.h
+    }
+
+    pub fn line_size(&self) -> usize {
+        self.inner.linesize[0] as usize
+    }
+
+    /// Palette indices, one byte per pixel, `line_size` bytes per row.
+    pub fn indices(&self) -> &'a [u8] {
+        unsafe {
+            slice::from_raw_parts(
+                self.inner.data[0],
+                self.line_size() * self.inner.h.max(0) as usize,
+            )
+        }
+    }
+
+    /// The palette as native-endian 0xAARRGGBB colors.
+    pub fn palette(&self) -> &'a [u32] {
+        if self.inner.data[1].is_null() {
+            return &[];
+        }
+
+        unsafe {
+            slice::from_raw_parts(
+                self.inner.data[1] as *const u32,
+                self.inner.nb_colors.max(0) as usize,
+            )
+        }
+    }
+}
+
+unsafe fn cstr_opt<'a>(ptr: *const std::ffi::c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        None
+    } else {
+        unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+    }
+}
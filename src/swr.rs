@@ -1,15 +1,17 @@
 use crate::error::{Error, Result};
 use crate::frame::Frame;
-use crate::{AVSampleFormat, ChannelLayout};
-use libavcodec_sys::{self as sys, av_channel_layout_default};
+use crate::{AVMatrixEncoding, AVSampleFormat, ChannelLayout, Sample};
+use libavcodec_sys as sys;
+use std::ffi::{CStr, CString};
 use std::ptr;
 
 /// Specifies the resampling algorithm to use
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub enum ResampleAlgorithm {
     /// Fast but lower quality resampling (linear interpolation)
     Linear,
     /// Balanced quality/speed resampling (cubic interpolation)
+    #[default]
     Cubic,
     /// High quality resampling using sinc-based algorithm with Blackman window
     /// The quality parameter determines the size of the filter (higher = better but slower)
@@ -19,8 +21,185 @@ pub enum ResampleAlgorithm {
     },
 }
 
+/// Dithering and noise shaping method used when reducing the bit depth,
+/// e.g. when converting to [`AVSampleFormat::S16`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DitherMethod {
+    #[default]
+    None,
+    Rectangular,
+    Triangular,
+    /// Triangular dither with high-pass filtered noise
+    TriangularHighpass,
+    /// Lipshitz noise shaping
+    Lipshitz,
+    /// F-weighted noise shaping
+    FWeighted,
+    /// Modified E-weighted noise shaping
+    ModifiedEWeighted,
+    /// Improved E-weighted noise shaping
+    ImprovedEWeighted,
+    /// Shibata noise shaping
+    Shibata,
+    /// Low Shibata noise shaping
+    LowShibata,
+    /// High Shibata noise shaping
+    HighShibata,
+}
+
+impl DitherMethod {
+    fn as_raw(self) -> sys::SwrDitherType {
+        match self {
+            DitherMethod::None => sys::SwrDitherType_SWR_DITHER_NONE,
+            DitherMethod::Rectangular => sys::SwrDitherType_SWR_DITHER_RECTANGULAR,
+            DitherMethod::Triangular => sys::SwrDitherType_SWR_DITHER_TRIANGULAR,
+            DitherMethod::TriangularHighpass => sys::SwrDitherType_SWR_DITHER_TRIANGULAR_HIGHPASS,
+            DitherMethod::Lipshitz => sys::SwrDitherType_SWR_DITHER_NS_LIPSHITZ,
+            DitherMethod::FWeighted => sys::SwrDitherType_SWR_DITHER_NS_F_WEIGHTED,
+            DitherMethod::ModifiedEWeighted => sys::SwrDitherType_SWR_DITHER_NS_MODIFIED_E_WEIGHTED,
+            DitherMethod::ImprovedEWeighted => sys::SwrDitherType_SWR_DITHER_NS_IMPROVED_E_WEIGHTED,
+            DitherMethod::Shibata => sys::SwrDitherType_SWR_DITHER_NS_SHIBATA,
+            DitherMethod::LowShibata => sys::SwrDitherType_SWR_DITHER_NS_LOW_SHIBATA,
+            DitherMethod::HighShibata => sys::SwrDitherType_SWR_DITHER_NS_HIGH_SHIBATA,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dither {
+    pub method: DitherMethod,
+    /// Scale of the dither noise, where 1.0 is one LSB of the output format.
+    pub scale: f64,
+}
+
+impl Default for Dither {
+    fn default() -> Self {
+        Dither {
+            method: DitherMethod::default(),
+            scale: 1.0,
+        }
+    }
+}
+
+/// The resampling engine to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResampleEngine {
+    /// libswresample's built-in resampler
+    #[default]
+    Swr,
+    /// The SoX resampler, if libswresample was built with libsoxr
+    Soxr,
+}
+
+impl ResampleEngine {
+    /// Whether the linked libswresample supports this engine.
+    pub fn is_available(self) -> bool {
+        match self {
+            ResampleEngine::Swr => true,
+            ResampleEngine::Soxr => {
+                let config = unsafe { CStr::from_ptr(sys::swresample_configuration()) };
+                config.to_string_lossy().contains("--enable-libsoxr")
+            }
+        }
+    }
+
+    fn as_raw(self) -> sys::SwrEngine {
+        match self {
+            ResampleEngine::Swr => sys::SwrEngine_SWR_ENGINE_SWR,
+            ResampleEngine::Soxr => sys::SwrEngine_SWR_ENGINE_SOXR,
+        }
+    }
+}
+
+/// A custom rematrixing matrix, where each coefficient is the gain of an
+/// input channel in an output channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixMatrix {
+    in_channels: usize,
+    out_channels: usize,
+    coefficients: Vec<f64>,
+}
+
+impl MixMatrix {
+    /// Creates a matrix that mutes every output channel.
+    pub fn new(in_channels: usize, out_channels: usize) -> Self {
+        MixMatrix {
+            in_channels,
+            out_channels,
+            coefficients: vec![0.0; in_channels * out_channels],
+        }
+    }
+
+    pub fn in_channels(&self) -> usize {
+        self.in_channels
+    }
+
+    pub fn out_channels(&self) -> usize {
+        self.out_channels
+    }
+
+    pub fn get(&self, out_channel: usize, in_channel: usize) -> f64 {
+        self.coefficients[out_channel * self.in_channels + in_channel]
+    }
+
+    pub fn set(&mut self, out_channel: usize, in_channel: usize, gain: f64) {
+        self.coefficients[out_channel * self.in_channels + in_channel] = gain;
+    }
+}
+
+/// Options applied to a resampler before it is initialized.
+#[derive(Debug, Clone, Default)]
+pub struct SwrOptions {
+    pub algorithm: ResampleAlgorithm,
+    /// Replaces the automatically computed rematrixing matrix. Its
+    /// dimensions must match the input and output channel counts.
+    pub matrix: Option<MixMatrix>,
+    /// For each input channel, the index of the input channel to read from
+    /// instead, or -1 to mute it.
+    pub channel_map: Option<Vec<i32>>,
+    /// Linear gain of the center channel when downmixing.
+    pub center_mix_level: Option<f64>,
+    /// Linear gain of the surround channels when downmixing.
+    pub surround_mix_level: Option<f64>,
+    /// Linear gain of the LFE channel when downmixing.
+    pub lfe_mix_level: Option<f64>,
+    /// Matrixed stereo encoding to use when downmixing, e.g. Dolby Surround
+    /// or Dolby Pro Logic II.
+    pub matrix_encoding: Option<AVMatrixEncoding>,
+    /// Dithering applied when reducing the sample bit depth.
+    pub dither: Option<Dither>,
+    /// The resampling engine. Initialization fails if it is not available.
+    pub engine: Option<ResampleEngine>,
+    /// Precision in bits of the soxr engine, between 15 and 33.
+    pub precision: Option<f64>,
+    /// Cutoff frequency of the resampling filter as a fraction of the
+    /// Nyquist frequency, between 0.0 and 1.0.
+    pub cutoff: Option<f64>,
+    /// Minimum timestamp drift in seconds that triggers compensation. Drift
+    /// compensation is disabled unless this is set.
+    pub min_compensation: Option<f64>,
+    /// Minimum timestamp drift in seconds that is corrected by dropping or
+    /// inserting silence instead of stretching the audio.
+    pub min_hard_compensation: Option<f64>,
+    /// Maximum factor by which the audio is stretched or squeezed to correct
+    /// drift.
+    pub max_soft_compensation: Option<f64>,
+    /// Duration in seconds over which soft compensation is spread.
+    pub compensation_duration: Option<f64>,
+}
+
 pub struct SwrContext {
     inner: *mut sys::SwrContext,
+    // libswresample keeps a pointer to the channel map instead of copying it
+    channel_map: Option<Box<[i32]>>,
+    in_format: AVSampleFormat,
+    in_channels: usize,
+    out_format: AVSampleFormat,
+    out_channels: usize,
+    // plane pointers for the planar conversions, allocated once up front so
+    // that converting does not allocate
+    in_planes: Vec<*const u8>,
+    out_planes: Vec<*mut u8>,
 }
 
 unsafe impl Send for SwrContext {}
@@ -53,6 +232,29 @@ impl SwrContext {
         out_sample_fmt: AVSampleFormat,
         out_channel_layout: ChannelLayout,
         algorithm: ResampleAlgorithm,
+    ) -> Result<Self> {
+        Self::get_context_with_options(
+            in_sample_rate,
+            in_sample_fmt,
+            in_channel_layout,
+            out_sample_rate,
+            out_sample_fmt,
+            out_channel_layout,
+            SwrOptions {
+                algorithm,
+                ..Default::default()
+            },
+        )
+    }
+
+    pub fn get_context_with_options(
+        in_sample_rate: usize,
+        in_sample_fmt: AVSampleFormat,
+        in_channel_layout: ChannelLayout,
+        out_sample_rate: usize,
+        out_sample_fmt: AVSampleFormat,
+        out_channel_layout: ChannelLayout,
+        options: SwrOptions,
     ) -> Result<Self> {
         let mut inner = ptr::null_mut();
         let ret = unsafe {
@@ -73,74 +275,154 @@ impl SwrContext {
             return Err(Error::new(ret));
         }
 
-        let mut inner = unsafe { &mut *inner };
+        // from here on, dropping the wrapper frees the context
+        let mut ctx = SwrContext {
+            inner,
+            channel_map: None,
+            in_format: in_sample_fmt,
+            in_channels: in_channel_layout.count(),
+            out_format: out_sample_fmt,
+            out_channels: out_channel_layout.count(),
+            in_planes: Vec::with_capacity(in_channel_layout.count()),
+            out_planes: Vec::with_capacity(out_channel_layout.count()),
+        };
 
         // Configure resampling algorithm
-        unsafe {
-            match algorithm {
-                ResampleAlgorithm::Linear => {
-                    // Linear interpolation - fast but lower quality
-                    sys::av_opt_set_int(
-                        inner as *mut _ as *mut _,
-                        "linear_interp\0".as_ptr() as *const i8,
-                        1,
-                        0,
-                    );
-                    sys::av_opt_set_int(
-                        inner as *mut _ as *mut _,
-                        "filter_type\0".as_ptr() as *const i8,
-                        0, // 0 = cubic
-                        0,
-                    );
-                }
-                ResampleAlgorithm::Cubic => {
-                    // Cubic interpolation - good balance of quality/speed
-                    sys::av_opt_set_int(
-                        inner as *mut _ as *mut _,
-                        "linear_interp\0".as_ptr() as *const i8,
-                        0,
-                        0,
-                    );
-                    sys::av_opt_set_int(
-                        inner as *mut _ as *mut _,
-                        "filter_type\0".as_ptr() as *const i8,
-                        0, // 0 = cubic
-                        0,
-                    );
-                }
-                ResampleAlgorithm::Sinc { quality } => {
-                    // Sinc resampling with configurable quality
-                    let quality = quality.clamp(0, 10);
-                    sys::av_opt_set_int(
-                        inner as *mut _ as *mut _,
-                        "linear_interp\0".as_ptr() as *const i8,
-                        0,
-                        0,
-                    );
-                    sys::av_opt_set_int(
-                        inner as *mut _ as *mut _,
-                        "filter_type\0".as_ptr() as *const i8,
-                        1, // 1 = sinc
-                        0,
-                    );
-                    sys::av_opt_set_int(
-                        inner as *mut _ as *mut _,
-                        "filter_size\0".as_ptr() as *const i8,
-                        (16 + quality * 8) as i64, // filter size based on quality
-                        0,
-                    );
-                }
+        match options.algorithm {
+            ResampleAlgorithm::Linear => {
+                // Linear interpolation - fast but lower quality
+                ctx.set_option_int("linear_interp", 1)?;
+                ctx.set_option_int("filter_type", 0)?; // 0 = cubic
+            }
+            ResampleAlgorithm::Cubic => {
+                // Cubic interpolation - good balance of quality/speed
+                ctx.set_option_int("linear_interp", 0)?;
+                ctx.set_option_int("filter_type", 0)?; // 0 = cubic
+            }
+            ResampleAlgorithm::Sinc { quality } => {
+                // Sinc resampling with configurable quality
+                let quality = quality.clamp(0, 10);
+                ctx.set_option_int("linear_interp", 0)?;
+                ctx.set_option_int("filter_type", 1)?; // 1 = sinc
+                // filter size based on quality
+                ctx.set_option_int("filter_size", (16 + quality * 8) as i64)?;
+            }
+        }
+
+        if let Some(engine) = options.engine {
+            ctx.set_option_int("resampler", engine.as_raw() as i64)?;
+        }
+
+        if let Some(precision) = options.precision {
+            ctx.set_option_double("precision", precision)?;
+        }
+
+        if let Some(cutoff) = options.cutoff {
+            ctx.set_option_double("cutoff", cutoff)?;
+        }
+
+        if let Some(seconds) = options.min_compensation {
+            ctx.set_option_double("min_comp", seconds)?;
+        }
+
+        if let Some(seconds) = options.min_hard_compensation {
+            ctx.set_option_double("min_hard_comp", seconds)?;
+        }
+
+        if let Some(factor) = options.max_soft_compensation {
+            ctx.set_option_double("max_soft_comp", factor)?;
+        }
+
+        if let Some(seconds) = options.compensation_duration {
+            ctx.set_option_double("comp_duration", seconds)?;
+        }
+
+        if let Some(dither) = options.dither {
+            ctx.set_option_int("dither_method", dither.method.as_raw() as i64)?;
+            ctx.set_option_double("dither_scale", dither.scale)?;
+        }
+
+        // Configure rematrixing
+        if let Some(level) = options.center_mix_level {
+            ctx.set_option_double("center_mix_level", level)?;
+        }
+
+        if let Some(level) = options.surround_mix_level {
+            ctx.set_option_double("surround_mix_level", level)?;
+        }
+
+        if let Some(level) = options.lfe_mix_level {
+            ctx.set_option_double("lfe_mix_level", level)?;
+        }
+
+        if let Some(encoding) = options.matrix_encoding {
+            ctx.set_option_int("matrix_encoding", encoding as i64)?;
+        }
+
+        if let Some(matrix) = &options.matrix {
+            if matrix.in_channels() != in_channel_layout.count()
+                || matrix.out_channels() != out_channel_layout.count()
+            {
+                return Err(Error::new(-libc::EINVAL));
+            }
+
+            let ret = unsafe {
+                sys::swr_set_matrix(
+                    ctx.inner,
+                    matrix.coefficients.as_ptr(),
+                    matrix.in_channels() as i32,
+                )
+            };
+
+            if ret < 0 {
+                return Err(Error::new(ret));
             }
         }
 
+        if let Some(channel_map) = options.channel_map {
+            if channel_map.len() != in_channel_layout.count() {
+                return Err(Error::new(-libc::EINVAL));
+            }
+
+            let channel_map = channel_map.into_boxed_slice();
+            let ret = unsafe { sys::swr_set_channel_mapping(ctx.inner, channel_map.as_ptr()) };
+
+            if ret < 0 {
+                return Err(Error::new(ret));
+            }
+
+            ctx.channel_map = Some(channel_map);
+        }
+
         // initialize the resampler
-        let ret = unsafe { sys::swr_init(inner) };
+        let ret = unsafe { sys::swr_init(ctx.inner) };
         if ret < 0 {
-            unsafe { sys::swr_free(&mut inner as *mut _ as *mut _) };
             return Err(Error::new(ret));
         }
 
-        Ok(SwrContext { inner })
+        Ok(ctx)
+    }
+
+    fn set_option_int(&mut self, name: &str, value: i64) -> Result<()> {
+        let name = CString::new(name).map_err(|_| Error::NulByte)?;
+        let ret = unsafe { sys::av_opt_set_int(self.inner as *mut _, name.as_ptr(), value, 0) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn set_option_double(&mut self, name: &str, value: f64) -> Result<()> {
+        let name = CString::new(name).map_err(|_| Error::NulByte)?;
+        let ret = unsafe { sys::av_opt_set_double(self.inner as *mut _, name.as_ptr(), value, 0) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(())
+        }
     }
 
     pub fn convert(&mut self, src: &Frame, dst: &mut Frame) -> Result<()> {
@@ -168,6 +450,177 @@ impl SwrContext {
         }
     }
 
+    /// Converts interleaved samples. `input` holds `in_channels` samples per
+    /// frame and `output` must be large enough for the converted samples
+    /// (see [`Self::max_out_samples`]).
+    ///
+    /// Returns the number of samples per channel written to `output`. Fails
+    /// with `EINVAL` if the formats are planar or `I`/`O` are not the sample
+    /// types of the configured formats.
+    pub fn convert_interleaved<I: Sample, O: Sample>(
+        &mut self,
+        input: &[I],
+        output: &mut [O],
+    ) -> Result<usize> {
+        self.check_interleaved::<I>(self.in_format, self.in_channels, input.len())?;
+        self.check_interleaved::<O>(self.out_format, self.out_channels, output.len())?;
+
+        let in_ptr = input.as_ptr() as *const u8;
+        let out_ptr = output.as_mut_ptr() as *mut u8;
+
+        self.convert_raw(
+            &[out_ptr],
+            output.len() / self.out_channels,
+            Some(&[in_ptr]),
+            input.len() / self.in_channels,
+        )
+    }
+
+    /// Converts planar samples, with one slice per channel.
+    ///
+    /// Returns the number of samples per channel written to each output
+    /// plane.
+    pub fn convert_planar<I: Sample, O: Sample>(
+        &mut self,
+        input: &[&[I]],
+        output: &mut [&mut [O]],
+    ) -> Result<usize> {
+        let in_samples = self.check_planar::<I>(
+            self.in_format,
+            self.in_channels,
+            input.iter().map(|plane| plane.len()),
+        )?;
+        let out_samples = self.check_planar::<O>(
+            self.out_format,
+            self.out_channels,
+            output.iter().map(|plane| plane.len()),
+        )?;
+
+        let mut in_planes = std::mem::take(&mut self.in_planes);
+        let mut out_planes = std::mem::take(&mut self.out_planes);
+        in_planes.extend(input.iter().map(|p| p.as_ptr() as *const u8));
+        out_planes.extend(output.iter_mut().map(|p| p.as_mut_ptr() as *mut u8));
+
+        let ret = self.convert_raw(&out_planes, out_samples, Some(&in_planes), in_samples);
+
+        in_planes.clear();
+        out_planes.clear();
+        self.in_planes = in_planes;
+        self.out_planes = out_planes;
+
+        ret
+    }
+
+    /// Drains samples buffered in the resampler into interleaved `output`.
+    pub fn flush_interleaved<O: Sample>(&mut self, output: &mut [O]) -> Result<usize> {
+        self.check_interleaved::<O>(self.out_format, self.out_channels, output.len())?;
+
+        let out_ptr = output.as_mut_ptr() as *mut u8;
+        self.convert_raw(&[out_ptr], output.len() / self.out_channels, None, 0)
+    }
+
+    /// Drains samples buffered in the resampler into planar `output`.
+    pub fn flush_planar<O: Sample>(&mut self, output: &mut [&mut [O]]) -> Result<usize> {
+        let out_samples = self.check_planar::<O>(
+            self.out_format,
+            self.out_channels,
+            output.iter().map(|plane| plane.len()),
+        )?;
+
+        let mut out_planes = std::mem::take(&mut self.out_planes);
+        out_planes.extend(output.iter_mut().map(|p| p.as_mut_ptr() as *mut u8));
+
+        let ret = self.convert_raw(&out_planes, out_samples, None, 0);
+
+        out_planes.clear();
+        self.out_planes = out_planes;
+
+        ret
+    }
+
+    fn convert_raw(
+        &mut self,
+        out: &[*mut u8],
+        out_count: usize,
+        input: Option<&[*const u8]>,
+        in_count: usize,
+    ) -> Result<usize> {
+        let ret = unsafe {
+            sys::swr_convert(
+                self.inner,
+                out.as_ptr(),
+                out_count as i32,
+                input.map_or(ptr::null(), |i| i.as_ptr()),
+                in_count as i32,
+            )
+        };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(ret as usize)
+        }
+    }
+
+    fn check_interleaved<T: Sample>(
+        &self,
+        format: AVSampleFormat,
+        channels: usize,
+        len: usize,
+    ) -> Result<()> {
+        if (format.is_planar() && channels > 1)
+            || !T::is_format(format)
+            || channels == 0
+            || len % channels != 0
+        {
+            return Err(Error::new(-libc::EINVAL));
+        }
+
+        Ok(())
+    }
+
+    // returns the number of samples per plane
+    fn check_planar<T: Sample>(
+        &self,
+        format: AVSampleFormat,
+        channels: usize,
+        mut lens: impl ExactSizeIterator<Item = usize>,
+    ) -> Result<usize> {
+        if (!format.is_planar() && channels > 1) || !T::is_format(format) || lens.len() != channels
+        {
+            return Err(Error::new(-libc::EINVAL));
+        }
+
+        let samples = lens.next().unwrap_or(0);
+        if lens.any(|len| len != samples) {
+            return Err(Error::new(-libc::EINVAL));
+        }
+
+        Ok(samples)
+    }
+
+    /// An upper bound on the number of samples per channel that the next
+    /// conversion of `in_samples` input samples will output, including
+    /// samples buffered from previous calls.
+    pub fn max_out_samples(&self, in_samples: usize) -> Result<usize> {
+        let ret = unsafe { sys::swr_get_out_samples(self.inner, in_samples as i32) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(ret as usize)
+        }
+    }
+
+    /// Converts the timestamp of the next input sample into the timestamp of
+    /// the next output sample, applying any timestamp compensation.
+    ///
+    /// Both timestamps are in units of `1 / (in_sample_rate * out_sample_rate)`
+    /// seconds.
+    pub fn next_pts(&mut self, pts: i64) -> i64 {
+        unsafe { sys::swr_next_pts(self.inner, pts) }
+    }
+
     pub fn get_out_samples(&self, in_samples: i64) -> i64 {
         unsafe {
             sys::av_rescale_rnd(
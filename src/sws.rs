@@ -1,9 +1,191 @@
-use crate::AVPixelFormat;
 use crate::error::{Error, Result};
 use crate::frame::Frame;
+use crate::{AVError, AVPixelFormat};
 use libavcodec_sys as sys;
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
 use std::ptr;
 
+/// Specifies the scaling algorithm to use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScaleAlgorithm {
+    /// Fast bilinear scaling, faster but lower quality than [`Self::Bilinear`]
+    FastBilinear,
+    Bilinear,
+    /// Bicubic scaling, tunable with [`SwsOptions::params`]
+    #[default]
+    Bicubic,
+    /// Experimental scaler
+    Experimental,
+    /// Nearest neighbor scaling
+    Point,
+    /// Area averaging, best suited for downscaling
+    Area,
+    /// Bicubic scaling for luma and bilinear scaling for chroma
+    Bicublin,
+    /// Gaussian scaling, tunable with [`SwsOptions::params`]
+    Gauss,
+    Sinc,
+    /// Lanczos scaling, tunable with [`SwsOptions::params`]
+    Lanczos,
+    /// Natural bicubic spline scaling
+    Spline,
+}
+
+impl ScaleAlgorithm {
+    fn as_raw(self) -> i32 {
+        let raw = match self {
+            ScaleAlgorithm::FastBilinear => sys::SWS_FAST_BILINEAR,
+            ScaleAlgorithm::Bilinear => sys::SWS_BILINEAR,
+            ScaleAlgorithm::Bicubic => sys::SWS_BICUBIC,
+            ScaleAlgorithm::Experimental => sys::SWS_X,
+            ScaleAlgorithm::Point => sys::SWS_POINT,
+            ScaleAlgorithm::Area => sys::SWS_AREA,
+            ScaleAlgorithm::Bicublin => sys::SWS_BICUBLIN,
+            ScaleAlgorithm::Gauss => sys::SWS_GAUSS,
+            ScaleAlgorithm::Sinc => sys::SWS_SINC,
+            ScaleAlgorithm::Lanczos => sys::SWS_LANCZOS,
+            ScaleAlgorithm::Spline => sys::SWS_SPLINE,
+        };
+
+        raw as i32
+    }
+}
+
+/// Flags that trade speed for accuracy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ScaleFlags {
+    /// Use accurate rounding instead of faster approximations.
+    pub accurate_rounding: bool,
+    /// Interpolate chroma to full resolution when converting from
+    /// subsampled formats, instead of duplicating chroma samples.
+    pub full_chroma_interpolation: bool,
+    /// Read chroma at full resolution from the input instead of
+    /// subsampling it first.
+    pub full_chroma_input: bool,
+    /// Produce identical output across CPUs and versions.
+    pub bit_exact: bool,
+    /// Use error diffusion dithering when reducing the bit depth.
+    pub error_diffusion: bool,
+}
+
+impl ScaleFlags {
+    fn as_raw(self) -> i32 {
+        let mut raw = 0;
+
+        if self.accurate_rounding {
+            raw |= sys::SWS_ACCURATE_RND;
+        }
+
+        if self.full_chroma_interpolation {
+            raw |= sys::SWS_FULL_CHR_H_INT;
+        }
+
+        if self.full_chroma_input {
+            raw |= sys::SWS_FULL_CHR_H_INP;
+        }
+
+        if self.bit_exact {
+            raw |= sys::SWS_BITEXACT;
+        }
+
+        if self.error_diffusion {
+            raw |= sys::SWS_ERROR_DIFFUSION;
+        }
+
+        raw as i32
+    }
+}
+
+/// Blur, sharpen and chroma shift applied to an image while it is scaled.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ScaleFilter {
+    /// Gaussian blur of the luma plane, as a standard deviation in pixels.
+    pub luma_blur: f32,
+    /// Gaussian blur of the chroma planes, as a standard deviation in pixels.
+    pub chroma_blur: f32,
+    /// Sharpening of the luma plane.
+    pub luma_sharpen: f32,
+    /// Sharpening of the chroma planes.
+    pub chroma_sharpen: f32,
+    /// Horizontal shift of the chroma planes, in pixels.
+    pub chroma_h_shift: f32,
+    /// Vertical shift of the chroma planes, in pixels.
+    pub chroma_v_shift: f32,
+}
+
+impl ScaleFilter {
+    // the caller must release the filter with sws_freeFilter
+    fn to_raw(self) -> Result<*mut sys::SwsFilter> {
+        let filter = unsafe {
+            sys::sws_getDefaultFilter(
+                self.luma_blur,
+                self.chroma_blur,
+                self.luma_sharpen,
+                self.chroma_sharpen,
+                self.chroma_h_shift,
+                self.chroma_v_shift,
+                0,
+            )
+        };
+
+        if filter.is_null() {
+            Err(Error::Alloc)
+        } else {
+            Ok(filter)
+        }
+    }
+}
+
+/// Options applied to a scaler when it is created.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SwsOptions {
+    pub algorithm: ScaleAlgorithm,
+    pub flags: ScaleFlags,
+    /// Tuning parameters of the algorithm, left at libswscale's defaults if
+    /// unset:
+    ///
+    /// - [`ScaleAlgorithm::Bicubic`]: the B and C parameters of the cubic
+    ///   filter (defaults 0 and 0.6)
+    /// - [`ScaleAlgorithm::Gauss`]: the sharpness of the gaussian (default 3)
+    /// - [`ScaleAlgorithm::Lanczos`]: the width of the window (default 3)
+    pub params: [Option<f64>; 2],
+    /// Filter applied to the source image.
+    pub src_filter: Option<ScaleFilter>,
+    /// Filter applied to the destination image.
+    pub dst_filter: Option<ScaleFilter>,
+    /// Number of threads used to scale each frame, or 0 to pick one per
+    /// CPU. Scaling is single-threaded unless this is set, or if libswscale
+    /// does not support threads.
+    pub threads: Option<usize>,
+}
+
+impl SwsOptions {
+    /// Bicubic scaling with the given B and C parameters.
+    pub fn bicubic(b: f64, c: f64) -> Self {
+        SwsOptions {
+            algorithm: ScaleAlgorithm::Bicubic,
+            params: [Some(b), Some(c)],
+            ..Default::default()
+        }
+    }
+
+    /// Lanczos scaling with a window of the given width.
+    pub fn lanczos(width: f64) -> Self {
+        SwsOptions {
+            algorithm: ScaleAlgorithm::Lanczos,
+            params: [Some(width), None],
+            ..Default::default()
+        }
+    }
+
+    fn raw_params(&self) -> [f64; 2] {
+        self.params
+            .map(|param| param.unwrap_or(sys::SWS_PARAM_DEFAULT as f64))
+    }
+}
+
 pub struct SwsContext {
     inner: *mut sys::SwsContext,
 }
@@ -18,27 +200,163 @@ impl SwsContext {
         dst_width: usize,
         dst_height: usize,
         dst_pix_fmt: AVPixelFormat,
-        flags: i32,
     ) -> Result<Self> {
+        Self::get_context_with_algorithm(
+            src_width,
+            src_height,
+            src_pix_fmt,
+            dst_width,
+            dst_height,
+            dst_pix_fmt,
+            ScaleAlgorithm::Bicubic,
+        )
+    }
+
+    pub fn get_context_with_algorithm(
+        src_width: usize,
+        src_height: usize,
+        src_pix_fmt: AVPixelFormat,
+        dst_width: usize,
+        dst_height: usize,
+        dst_pix_fmt: AVPixelFormat,
+        algorithm: ScaleAlgorithm,
+    ) -> Result<Self> {
+        Self::get_context_with_options(
+            src_width,
+            src_height,
+            src_pix_fmt,
+            dst_width,
+            dst_height,
+            dst_pix_fmt,
+            SwsOptions {
+                algorithm,
+                ..Default::default()
+            },
+        )
+    }
+
+    pub fn get_context_with_options(
+        src_width: usize,
+        src_height: usize,
+        src_pix_fmt: AVPixelFormat,
+        dst_width: usize,
+        dst_height: usize,
+        dst_pix_fmt: AVPixelFormat,
+        options: SwsOptions,
+    ) -> Result<Self> {
+        let filters = Filters::new(&options)?;
+        let params = options.raw_params();
+
+        let inner = unsafe { sys::sws_alloc_context() };
+        if inner.is_null() {
+            return Err(Error::Alloc);
+        }
+
+        // from here on, dropping the wrapper frees the context
+        let mut ctx = SwsContext { inner };
+
+        // this is what sws_getContext does, except that the thread count
+        // has to be set before the context is initialized
+        ctx.set_option_int("srcw", src_width as i64)?;
+        ctx.set_option_int("srch", src_height as i64)?;
+        ctx.set_option_int("src_format", src_pix_fmt as i64)?;
+        ctx.set_option_int("dstw", dst_width as i64)?;
+        ctx.set_option_int("dsth", dst_height as i64)?;
+        ctx.set_option_int("dst_format", dst_pix_fmt as i64)?;
+        ctx.set_option_int(
+            "sws_flags",
+            (options.algorithm.as_raw() | options.flags.as_raw()) as i64,
+        )?;
+        ctx.set_option_double("param0", params[0])?;
+        ctx.set_option_double("param1", params[1])?;
+
+        // older libswscale builds have no threads option, and scale on the
+        // calling thread instead
+        if let Some(threads) = options.threads {
+            match ctx.set_option_int("threads", threads as i64) {
+                Ok(()) | Err(Error::Av(AVError::OptionNotFound)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        // the filter coefficients are copied into the context
+        let ret = unsafe { sys::sws_init_context(ctx.inner, filters.src, filters.dst) };
+        if ret < 0 {
+            return Err(Error::new(ret));
+        }
+
+        Ok(ctx)
+    }
+
+    /// Returns `context` unchanged if it was created with the same
+    /// parameters, and otherwise frees it and creates a new context.
+    ///
+    /// `src` and `dst` are the width, height and pixel format of the source
+    /// and destination images.
+    pub fn get_cached_context(
+        context: Option<Self>,
+        src: (usize, usize, AVPixelFormat),
+        dst: (usize, usize, AVPixelFormat),
+        options: SwsOptions,
+    ) -> Result<Self> {
+        let (src_width, src_height, src_pix_fmt) = src;
+        let (dst_width, dst_height, dst_pix_fmt) = dst;
+        let filters = Filters::new(&options)?;
+        let params = options.raw_params();
+
+        // ownership passes to sws_getCachedContext, which frees the context
+        // if it cannot be reused, even on failure
+        let context = context.map_or(ptr::null_mut(), |ctx| ManuallyDrop::new(ctx).inner);
+
         let inner = unsafe {
-            sys::sws_getContext(
+            sys::sws_getCachedContext(
+                context,
                 src_width as i32,
                 src_height as i32,
                 src_pix_fmt as i32,
                 dst_width as i32,
                 dst_height as i32,
                 dst_pix_fmt as i32,
-                flags,
-                ptr::null_mut(),
-                ptr::null_mut(),
-                ptr::null_mut(),
+                options.algorithm.as_raw() | options.flags.as_raw(),
+                filters.src,
+                filters.dst,
+                params.as_ptr(),
             )
         };
 
         if inner.is_null() {
-            Err(Error::Alloc)
+            return Err(Error::Alloc);
+        }
+
+        // a context that could not be reused is rebuilt without the thread
+        // count, so build it again ourselves
+        if inner != context && options.threads.is_some() {
+            unsafe { sys::sws_freeContext(inner) };
+
+            return Self::get_context_with_options(
+                src_width,
+                src_height,
+                src_pix_fmt,
+                dst_width,
+                dst_height,
+                dst_pix_fmt,
+                options,
+            );
+        }
+
+        Ok(SwsContext { inner })
+    }
+
+    /// Scales `src` into `dst`. If `dst` has no buffer yet, one is allocated
+    /// with the destination size and format. The properties of `src`, such
+    /// as its timestamps, are not copied, see [`Frame::copy_props`].
+    pub fn scale_frame(&mut self, src: &Frame, dst: &mut Frame) -> Result<()> {
+        let ret = unsafe { sys::sws_scale_frame(self.inner, dst.as_mut_ptr(), src.as_ptr()) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
         } else {
-            Ok(SwsContext { inner })
+            Ok(())
         }
     }
 
@@ -61,6 +379,91 @@ impl SwsContext {
             Ok(())
         }
     }
+
+    /// Starts scaling `src` into `dst` slice by slice, with
+    /// [`SliceScale::send_slice`] and [`SliceScale::receive_slice`]. If `dst`
+    /// has no buffer yet, one is allocated as in [`Self::scale_frame`].
+    ///
+    /// The frame is finished when the returned guard is dropped.
+    pub fn frame_start<'a>(
+        &'a mut self,
+        src: &'a Frame,
+        dst: &'a mut Frame,
+    ) -> Result<SliceScale<'a>> {
+        let ret = unsafe { sys::sws_frame_start(self.inner, dst.as_mut_ptr(), src.as_ptr()) };
+
+        if ret < 0 {
+            // drops any frame references taken before the failure
+            unsafe { sys::sws_frame_end(self.inner) };
+            return Err(Error::new(ret));
+        }
+
+        Ok(SliceScale {
+            context: self,
+            _frames: PhantomData,
+        })
+    }
+
+    /// The row alignment required for destination slices.
+    pub fn receive_slice_alignment(&self) -> usize {
+        unsafe { sys::sws_receive_slice_alignment(self.inner) as usize }
+    }
+
+    /// Scales raw image planes, see `sws_scale`.
+    ///
+    /// # Safety
+    ///
+    /// The planes must match the size and formats of the context, and hold at
+    /// least `src_slice_height` source rows starting at `src_slice_y`.
+    pub(crate) unsafe fn scale_planes(
+        &mut self,
+        src: &[*const u8],
+        src_stride: &[i32],
+        src_slice_y: i32,
+        src_slice_height: i32,
+        dst: &[*mut u8],
+        dst_stride: &[i32],
+    ) -> Result<()> {
+        let ret = unsafe {
+            sys::sws_scale(
+                self.inner,
+                src.as_ptr(),
+                src_stride.as_ptr(),
+                src_slice_y,
+                src_slice_height,
+                dst.as_ptr(),
+                dst_stride.as_ptr(),
+            )
+        };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn set_option_int(&mut self, name: &str, value: i64) -> Result<()> {
+        let name = CString::new(name).map_err(|_| Error::NulByte)?;
+        let ret = unsafe { sys::av_opt_set_int(self.inner as *mut _, name.as_ptr(), value, 0) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn set_option_double(&mut self, name: &str, value: f64) -> Result<()> {
+        let name = CString::new(name).map_err(|_| Error::NulByte)?;
+        let ret = unsafe { sys::av_opt_set_double(self.inner as *mut _, name.as_ptr(), value, 0) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl Drop for SwsContext {
@@ -70,3 +473,88 @@ impl Drop for SwsContext {
         }
     }
 }
+
+/// A frame being scaled slice by slice, started with
+/// [`SwsContext::frame_start`]. The context references both frames until
+/// this is dropped.
+pub struct SliceScale<'a> {
+    context: &'a mut SwsContext,
+    _frames: PhantomData<(&'a Frame, &'a mut Frame)>,
+}
+
+impl SliceScale<'_> {
+    /// Signals that `height` rows of the source frame starting at row `start`
+    /// are ready to be scaled.
+    pub fn send_slice(&mut self, start: usize, height: usize) -> Result<()> {
+        let ret = unsafe { sys::sws_send_slice(self.context.inner, start as u32, height as u32) };
+
+        if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Scales `height` rows of the destination frame starting at row `start`.
+    /// Both must be multiples of [`Self::receive_slice_alignment`], except for
+    /// the last slice of the frame.
+    ///
+    /// Returns `false` if more source rows must be sent first.
+    pub fn receive_slice(&mut self, start: usize, height: usize) -> Result<bool> {
+        let ret =
+            unsafe { sys::sws_receive_slice(self.context.inner, start as u32, height as u32) };
+
+        if ret == AVError::Again as i32 {
+            Ok(false)
+        } else if ret < 0 {
+            Err(Error::new(ret))
+        } else {
+            Ok(true)
+        }
+    }
+
+    /// The row alignment required for destination slices.
+    pub fn receive_slice_alignment(&self) -> usize {
+        self.context.receive_slice_alignment()
+    }
+}
+
+impl Drop for SliceScale<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::sws_frame_end(self.context.inner) }
+    }
+}
+
+// the raw filters for a context being created, freed once it exists
+struct Filters {
+    src: *mut sys::SwsFilter,
+    dst: *mut sys::SwsFilter,
+}
+
+impl Filters {
+    fn new(options: &SwsOptions) -> Result<Self> {
+        let mut filters = Filters {
+            src: ptr::null_mut(),
+            dst: ptr::null_mut(),
+        };
+
+        if let Some(filter) = options.src_filter {
+            filters.src = filter.to_raw()?;
+        }
+
+        if let Some(filter) = options.dst_filter {
+            filters.dst = filter.to_raw()?;
+        }
+
+        Ok(filters)
+    }
+}
+
+impl Drop for Filters {
+    fn drop(&mut self) {
+        unsafe {
+            sys::sws_freeFilter(self.src);
+            sys::sws_freeFilter(self.dst);
+        }
+    }
+}
@@ -37,26 +37,38 @@ fn main() {
         .allowlist_type("AVStream")
         .allowlist_type("AVFormatContext")
         .allowlist_type("AVCodecParserContext")
+        .allowlist_type("AVBSFContext")
         .allowlist_type("AVCodecParameters")
+        .allowlist_type("AVSubtitle")
         .allowlist_type("AVRational")
         .allowlist_type("AVDictionary")
         .allowlist_type("AVOption")
+        .allowlist_type("AVMasteringDisplayMetadata")
+        .allowlist_type("AVContentLightMetadata")
+        .allowlist_type("AVMotionVector")
+        .allowlist_type("AVReplayGain")
         .allowlist_type("SwsContext")
         .allowlist_type("SwsFilter")
         .allowlist_type("SwrContext")
+        .allowlist_type("SwrDitherType")
+        .allowlist_type("SwrEngine")
         .allowlist_item("SWS_.*")
+        .allowlist_item("FF_LOSS_.*")
         .allowlist_item("AVERROR_.*")
         .allowlist_item("AVError.*")
         .allowlist_item("AV_.*")
         .allowlist_item("AVIO.*")
         .allowlist_item("AVFMT.*")
+        .allowlist_item("AVPROBE_.*")
         .allowlist_item("avrs_.*")
         .allowlist_function("av_.*")
         .allowlist_function("avio_.*")
         .allowlist_function("sws_.*")
         .allowlist_function("swr_.*")
+        .allowlist_function("swresample_.*")
         .allowlist_function("avformat_.*")
         .allowlist_function("avcodec_.*")
+        .allowlist_function("avsubtitle_.*")
         .allowlist_function("avutil_.*")
         .allowlist_function("avfilter_.*")
         .allowlist_function("avdevice_.*")
@@ -77,6 +89,9 @@ fn main() {
         .write_to_file(out_path.join("bindings.rs"))
         .expect("couldn't write libavcodec bindings");
 
+    // lets the main crate generate its enums from the bindings
+    println!("cargo:bindings={}", out_path.join("bindings.rs").display());
+
     cc::Build::new()
         .includes(include_paths)
         .file("wrapper.cpp")
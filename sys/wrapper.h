@@ -1,15 +1,20 @@
 #include "libavcodec/avcodec.h"
+#include "libavcodec/bsf.h"
 #include "libavformat/avformat.h"
 #include "libavutil/avutil.h"
 #include "libswresample/swresample.h"
 #include "libswscale/swscale.h"
 
+#include "libavutil/display.h"
 #include "libavutil/imgutils.h"
 #include "libavutil/log.h"
+#include "libavutil/mastering_display_metadata.h"
 #include "libavutil/mem.h"
+#include "libavutil/motion_vector.h"
 #include "libavutil/opt.h"
 #include "libavutil/pixdesc.h"
 #include "libavutil/rational.h"
+#include "libavutil/replaygain.h"
 
 enum AVError {
   BSF_NOT_FOUND = AVERROR_BSF_NOT_FOUND,
@@ -27,6 +32,7 @@ enum AVError {
   OPTION_NOT_FOUND = AVERROR_OPTION_NOT_FOUND,
   PATCHWELCOME = AVERROR_PATCHWELCOME,
   PROTOCOL_NOT_FOUND = AVERROR_PROTOCOL_NOT_FOUND,
+  STREAM_NOT_FOUND = AVERROR_STREAM_NOT_FOUND,
   AGAIN = AVERROR(EAGAIN),
 };
 
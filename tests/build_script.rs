@@ -0,0 +1,5 @@
+//! Runs the unit tests of the build script, which cargo does not do itself.
+
+#[allow(dead_code)]
+#[path = "../build.rs"]
+mod build_script;